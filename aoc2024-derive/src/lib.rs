@@ -0,0 +1,444 @@
+//! Derive macros of the `aoc2024` crate. Use them through `aoc2024::parse`,
+//! the generated code refers to the types defined there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::ParseStream;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, LitChar, LitStr,
+    Token,
+};
+
+/// Implement `FromLine` and `FromStr` for a struct by describing its line
+/// with a pattern. Every `{}` of the pattern stands for a value, `{...}` at the
+/// end of the pattern for a list of values separated by whitespace:
+///
+/// ```ignore
+/// #[derive(FromLine)]
+/// #[aoc(pattern = "p={},{} v={},{}")]
+/// pub struct Robot {
+///     pub position: Position,
+///     pub velocity: Position,
+/// }
+/// ```
+///
+/// The fields take the values in their order, most types use one value, a
+/// `Position` or a `Location` two and a `Vec` the values of a `{...}`.
+#[proc_macro_derive(FromLine, attributes(aoc))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_line(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Segment {
+    Literal(String),
+    Value,
+    Rest,
+}
+
+impl Segment {
+    fn is_placeholder(&self) -> bool {
+        !matches!(self, Segment::Literal(_))
+    }
+}
+
+fn pattern_of(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut pattern = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("aoc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("pattern") {
+                pattern = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown attribute, expected `pattern`"))
+            }
+        })?;
+    }
+    pattern.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "FromLine needs a pattern, like #[aoc(pattern = \"{}: {...}\")]",
+        )
+    })
+}
+
+fn segments_of(pattern: &LitStr) -> syn::Result<Vec<Segment>> {
+    let text = pattern.value();
+    let mut segments = vec![];
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("{...}") {
+            segments.push(Segment::Rest);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("{}") {
+            segments.push(Segment::Value);
+            rest = after;
+        } else {
+            if rest.starts_with('{') {
+                return Err(Error::new_spanned(
+                    pattern,
+                    "only `{}` and `{...}` can be used as placeholders",
+                ));
+            }
+            let end = rest.find('{').unwrap_or(rest.len());
+            segments.push(Segment::Literal(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+
+    // Without some text in between there is no way to tell where one value
+    // ends and the next starts
+    if segments
+        .windows(2)
+        .any(|pair| pair[0].is_placeholder() && pair[1].is_placeholder())
+    {
+        return Err(Error::new_spanned(
+            pattern,
+            "placeholders must be separated by some text",
+        ));
+    }
+    if let Some(position) = segments.iter().position(|s| matches!(s, Segment::Rest)) {
+        if position != segments.len() - 1 {
+            return Err(Error::new_spanned(
+                pattern,
+                "`{...}` can only be used at the end of the pattern",
+            ));
+        }
+    }
+    Ok(segments)
+}
+
+fn expand_from_line(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let pattern = pattern_of(input)?;
+    let segments = segments_of(&pattern)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            name,
+            "FromLine can only be derived for structs",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "FromLine can't be derived for generic structs",
+        ));
+    }
+
+    let types: Vec<_> = data.fields.iter().map(|field| &field.ty).collect();
+    let variables: Vec<_> = (0..types.len())
+        .map(|index| format_ident!("field{}", index))
+        .collect();
+    let construct = match &data.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { #name { #(#names: #variables),* } }
+        }
+        Fields::Unnamed(_) => quote! { #name(#(#variables),*) },
+        Fields::Unit => quote! { #name },
+    };
+
+    let pattern_segments = segments.iter().map(|segment| match segment {
+        Segment::Literal(text) => quote! { ::aoc2024::parse::Segment::Literal(#text) },
+        Segment::Value => quote! { ::aoc2024::parse::Segment::Value },
+        Segment::Rest => quote! { ::aoc2024::parse::Segment::Rest },
+    });
+    let placeholders = segments.iter().filter(|s| s.is_placeholder()).count();
+
+    // Every field takes its captures right after the ones of the fields in
+    // front of it
+    let starts = (0..types.len()).map(|index| {
+        let previous = &types[..index];
+        quote! { 0 #(+ <#previous as ::aoc2024::parse::FromCaptures>::CAPTURES)* }
+    });
+
+    Ok(quote! {
+        const _: () = assert!(
+            0 #(+ <#types as ::aoc2024::parse::FromCaptures>::CAPTURES)* == #placeholders,
+            "the fields don't match the placeholders of the pattern"
+        );
+
+        impl ::aoc2024::parse::FromLine for #name {
+            fn from_line(line: &str) -> ::std::result::Result<Self, ::aoc2024::parse::ParseError> {
+                const PATTERN: &[::aoc2024::parse::Segment] = &[#(#pattern_segments),*];
+                let captures = ::aoc2024::parse::match_pattern(line, PATTERN)?;
+                #(
+                    let start = #starts;
+                    let #variables = <#types as ::aoc2024::parse::FromCaptures>::from_captures(
+                        line,
+                        &captures[start..start + <#types as ::aoc2024::parse::FromCaptures>::CAPTURES],
+                    )?;
+                )*
+                Ok(#construct)
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = ::aoc2024::parse::ParseError;
+
+            fn from_str(line: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as ::aoc2024::parse::FromLine>::from_line(line)
+            }
+        }
+    })
+}
+
+/// Implement `Tile`, `TryFrom<char>` and `Display` for the tiles of a grid.
+/// Every variant of an enum gets its character:
+///
+/// ```ignore
+/// #[derive(Tile)]
+/// pub enum Place {
+///     #[tile('.', marker = '@')]
+///     Floor,
+///     #[tile('O')]
+///     Box,
+///     #[tile('#')]
+///     Wall,
+/// }
+/// ```
+///
+/// A `marker` is a character that stands for a location of the grid, like the
+/// robot `@`, on a tile of its variant. The fields of a variant start out as
+/// their default, or as the `value` given like `#[tile('.', value = i32::MAX)]`.
+///
+/// A struct that can be any character keeps it in a field marked with
+/// `#[tile(char)]`, the other fields start out as their default.
+#[proc_macro_derive(Tile, attributes(tile))]
+pub fn derive_tile(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_tile(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// The `#[tile('c', marker = 'm', value = expr)]` of a variant
+struct TileAttribute {
+    character: LitChar,
+    markers: Vec<LitChar>,
+    value: Option<Expr>,
+}
+
+fn tile_attribute_of(attrs: &[Attribute], variant: &Ident) -> syn::Result<TileAttribute> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("tile"))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                variant,
+                "every variant needs a character, like #[tile('#')]",
+            )
+        })?;
+    attr.parse_args_with(|input: ParseStream| {
+        let mut tile = TileAttribute {
+            character: input.parse()?,
+            markers: vec![],
+            value: None,
+        };
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "marker" {
+                tile.markers.push(input.parse()?);
+            } else if key == "value" {
+                tile.value = Some(input.parse()?);
+            } else {
+                return Err(Error::new_spanned(
+                    key,
+                    "unknown attribute, expected `marker` or `value`",
+                ));
+            }
+        }
+        Ok(tile)
+    })
+}
+
+fn expand_tile(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "Tile can't be derived for generic types",
+        ));
+    }
+
+    let body = match &input.data {
+        Data::Enum(data) => expand_tile_enum(data)?,
+        Data::Struct(data) => expand_tile_struct(name, &data.fields)?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                name,
+                "Tile can only be derived for enums and structs",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl ::aoc2024::tile::Tile for #name {
+            #body
+        }
+
+        impl ::std::convert::TryFrom<char> for #name {
+            type Error = ::aoc2024::parse::ParseError;
+
+            fn try_from(ch: char) -> ::std::result::Result<Self, Self::Error> {
+                <Self as ::aoc2024::tile::Tile>::from_char(ch).ok_or_else(|| {
+                    let text = ch.to_string();
+                    ::aoc2024::parse::ParseError::new(
+                        &text,
+                        0,
+                        &text,
+                        &::aoc2024::tile::unknown_tile::<Self>(),
+                    )
+                })
+            }
+        }
+
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                use ::std::fmt::Write;
+                f.write_char(<Self as ::aoc2024::tile::Tile>::to_char(self))
+            }
+        }
+    })
+}
+
+fn expand_tile_enum(data: &syn::DataEnum) -> syn::Result<TokenStream2> {
+    let mut characters = String::new();
+    let mut markers = vec![];
+    let mut from_char = vec![];
+    let mut to_char = vec![];
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let tile = tile_attribute_of(&variant.attrs, ident)?;
+
+        for character in std::iter::once(&tile.character).chain(&tile.markers) {
+            if characters.contains(character.value()) {
+                return Err(Error::new_spanned(
+                    character,
+                    "this character is already used by another tile",
+                ));
+            }
+            characters.push(character.value());
+        }
+
+        let value = |field: &syn::Field| match &tile.value {
+            Some(value) => quote! { #value },
+            None => {
+                let ty = &field.ty;
+                quote! { <#ty as ::std::default::Default>::default() }
+            }
+        };
+        if tile.value.is_some() && variant.fields.len() != 1 {
+            return Err(Error::new_spanned(
+                ident,
+                "a `value` can only be given for a variant with one field",
+            ));
+        }
+        let construct = match &variant.fields {
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                let values = fields.named.iter().map(value);
+                quote! { Self::#ident { #(#names: #values),* } }
+            }
+            Fields::Unnamed(fields) => {
+                let values = fields.unnamed.iter().map(value);
+                quote! { Self::#ident(#(#values),*) }
+            }
+            Fields::Unit => quote! { Self::#ident },
+        };
+
+        let character = &tile.character;
+        let variant_markers = &tile.markers;
+        from_char.push(quote! { #character #(| #variant_markers)* => Some(#construct), });
+        to_char.push(quote! { Self::#ident { .. } => #character, });
+        markers.extend(tile.markers);
+    }
+
+    let is_marker = (!markers.is_empty()).then(|| {
+        quote! {
+            fn is_marker(ch: char) -> bool {
+                matches!(ch, #(#markers)|*)
+            }
+        }
+    });
+
+    Ok(quote! {
+        const CHARACTERS: &'static str = #characters;
+
+        fn from_char(ch: char) -> ::std::option::Option<Self> {
+            match ch {
+                #(#from_char)*
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                #(#to_char)*
+            }
+        }
+
+        #is_marker
+    })
+}
+
+fn expand_tile_struct(name: &Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let is_character = |field: &&syn::Field| {
+        field.attrs.iter().any(|attr| {
+            attr.path().is_ident("tile")
+                && attr
+                    .parse_args::<Ident>()
+                    .is_ok_and(|argument| argument == "char")
+        })
+    };
+    let Fields::Named(named) = fields else {
+        return Err(Error::new_spanned(
+            name,
+            "Tile can only be derived for structs with named fields",
+        ));
+    };
+    let character = named
+        .named
+        .iter()
+        .find(is_character)
+        .and_then(|field| field.ident.as_ref())
+        .ok_or_else(|| {
+            Error::new_spanned(
+                name,
+                "one field needs to hold the character, mark it with #[tile(char)]",
+            )
+        })?;
+    let others: Vec<_> = named
+        .named
+        .iter()
+        .filter(|field| field.ident.as_ref() != Some(character))
+        .collect();
+    let other_names = others.iter().map(|field| &field.ident);
+    let other_types = others.iter().map(|field| &field.ty);
+
+    Ok(quote! {
+        const CHARACTERS: &'static str = "";
+
+        fn from_char(ch: char) -> ::std::option::Option<Self> {
+            Some(Self {
+                #character: ch,
+                #(#other_names: <#other_types as ::std::default::Default>::default()),*
+            })
+        }
+
+        fn to_char(&self) -> char {
+            self.#character
+        }
+    })
+}
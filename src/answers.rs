@@ -0,0 +1,250 @@
+// The accepted answers of the days, kept next to the puzzle input, so that
+// changes to the shared library can be checked against the real answers.
+
+use crate::runner::{describe, DayReport, PartResult};
+use crate::{Answer, AocError, AocErrorType, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{PartReport, Timing};
+
+    fn report_with(answers: &[(Part, Answer)]) -> DayReport {
+        DayReport {
+            day: 1,
+            parse: Timing::default(),
+            parts: answers
+                .iter()
+                .map(|(part, answer)| PartReport {
+                    part: *part,
+                    answer: Ok(answer.clone()),
+                    timing: Timing::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_numbers_and_text() {
+        let known = KnownAnswers::parse("part1 = 1234\npart2 = \"4,6,3,5\"\n").unwrap();
+
+        assert_eq!(known.get(Part::One), Some(&Answer::Number(1234)));
+        assert_eq!(known.get(Part::Two), Some(&Answer::Text("4,6,3,5".into())));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys_and_values() {
+        assert!(KnownAnswers::parse("part3 = 1").is_err());
+        assert!(KnownAnswers::parse("part1 = 1.5").is_err());
+        assert!(KnownAnswers::parse("part1 = ").is_err());
+    }
+
+    #[test]
+    fn test_written_answers_can_be_read_back() {
+        let mut known = KnownAnswers::default();
+        known.insert(Part::One, Answer::Number(1234));
+        known.insert(Part::Two, Answer::Text("6,1".into()));
+
+        assert_eq!(known.to_toml(), "part1 = 1234\npart2 = \"6,1\"\n");
+        assert_eq!(KnownAnswers::parse(&known.to_toml()).unwrap(), known);
+    }
+
+    #[test]
+    fn test_verify_report() {
+        let known = KnownAnswers::parse("part1 = 11").unwrap();
+        let report = report_with(&[
+            (Part::One, Answer::Number(11)),
+            (Part::Two, Answer::Number(31)),
+        ]);
+
+        let verdicts = known.verify(&report);
+
+        assert_eq!(verdicts[0].verdict, Verdict::Pass);
+        assert_eq!(verdicts[1].verdict, Verdict::Missing);
+
+        let known = KnownAnswers::parse("part1 = 12").unwrap();
+        let verdicts = known.verify(&report);
+        assert_eq!(
+            verdicts[0].verdict,
+            Verdict::Fail {
+                expected: Answer::Number(12)
+            }
+        );
+    }
+
+    #[test]
+    fn test_text_answer_matches_number() {
+        let known = KnownAnswers::parse("part1 = \"11\"").unwrap();
+        let report = report_with(&[(Part::One, Answer::Number(11))]);
+
+        assert_eq!(known.verify(&report)[0].verdict, Verdict::Pass);
+    }
+}
+
+/// The location of the accepted answers for the given day
+pub fn answers_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("input_data/day{}/answers.toml", day))
+}
+
+/// Answers are stored as TOML integers or, when they aren't numbers, strings
+pub fn answer_from_toml(value: Value) -> Option<Answer> {
+    match value {
+        Value::Integer(number) => Some(Answer::Number(number)),
+        Value::String(text) => Some(Answer::Text(text)),
+        _ => None,
+    }
+}
+
+pub fn answer_to_toml(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(number) => Value::Integer(*number),
+        Answer::Text(text) => Value::String(text.clone()),
+    }
+}
+
+/// The accepted answers of one day, as stored in an `answers.toml` like
+///
+/// ```toml
+/// part1 = 1234
+/// part2 = "4,6,3,5,6,3,5,2,1,0"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<Part, Answer>,
+}
+
+impl KnownAnswers {
+    pub fn parse(source: &str) -> Result<KnownAnswers, AocError> {
+        let invalid = |reason: String| AocError::new(AocErrorType::InvalidAnswersFile(reason));
+
+        let table: Table = source
+            .parse()
+            .map_err(|e: toml::de::Error| invalid(e.to_string()))?;
+        KnownAnswers::from_table(table)
+    }
+
+    /// Take the answers from a table that only contains `part1` and `part2`
+    pub fn from_table(table: Table) -> Result<KnownAnswers, AocError> {
+        let invalid = |reason: String| AocError::new(AocErrorType::InvalidAnswersFile(reason));
+
+        let mut answers = BTreeMap::new();
+        for (key, value) in table {
+            let part = match key.as_str() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(invalid(format!("unknown key '{}'", key))),
+            };
+            let answer = answer_from_toml(value)
+                .ok_or(invalid(format!("'{}' must be a number or a string", key)))?;
+            answers.insert(part, answer);
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    /// Load the answers of the given day. A day without an answers file simply
+    /// has no known answers yet.
+    pub fn load(day: u32) -> Result<KnownAnswers, AocError> {
+        KnownAnswers::load_from(&answers_path(day))
+    }
+
+    /// Load answers from any file, a missing file means no known answers
+    pub fn load_from(path: &Path) -> Result<KnownAnswers, AocError> {
+        match read_to_string(path) {
+            Ok(source) => KnownAnswers::parse(&source),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(error) => Err(AocError::new(AocErrorType::InvalidAnswersFile(
+                error.to_string(),
+            ))),
+        }
+    }
+
+    /// Store the answers of the given day in its answers file
+    pub fn save(&self, day: u32) -> Result<(), AocError> {
+        fs::write(answers_path(day), self.to_toml())
+            .map_err(|e| AocError::new(AocErrorType::WriteFailed(e.to_string())))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let table: Table = self
+            .answers
+            .iter()
+            .map(|(part, answer)| (format!("part{}", part), answer_to_toml(answer)))
+            .collect();
+        table.to_string()
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        self.answers.get(&part)
+    }
+
+    pub fn insert(&mut self, part: Part, answer: Answer) {
+        self.answers.insert(part, answer);
+    }
+
+    /// Compare every part of the report with the known answers
+    pub fn verify(&self, report: &DayReport) -> Vec<PartVerdict> {
+        report
+            .parts
+            .iter()
+            .map(|part_report| {
+                let verdict = match (self.get(part_report.part), &part_report.answer) {
+                    (None, _) => Verdict::Missing,
+                    // Compare the printed form, so that "42" in the file also matches 42
+                    (Some(expected), Ok(answer)) if expected.to_string() == answer.to_string() => {
+                        Verdict::Pass
+                    }
+                    (Some(expected), _) => Verdict::Fail {
+                        expected: expected.clone(),
+                    },
+                };
+                PartVerdict {
+                    part: part_report.part,
+                    answer: part_report.answer.clone(),
+                    verdict,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartVerdict {
+    pub part: Part,
+    pub answer: PartResult,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for PartVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.verdict {
+            Verdict::Pass => write!(f, "part {}: pass", self.part),
+            Verdict::Fail { expected } => write!(
+                f,
+                "part {}: FAIL (expected {}, got {})",
+                self.part,
+                expected,
+                describe(&self.answer)
+            ),
+            Verdict::Missing => write!(
+                f,
+                "part {}: missing (got {})",
+                self.part,
+                describe(&self.answer)
+            ),
+        }
+    }
+}
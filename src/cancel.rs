@@ -0,0 +1,82 @@
+// Cooperative cancellation of long running parts. The runner gives every part
+// a token and cancels it when the part runs out of time. A part can't be
+// stopped from the outside, so long loops should check the token of their
+// thread now and then and give up once it is cancelled:
+//
+//     let token = CancellationToken::current();
+//     loop {
+//         if token.is_cancelled() {
+//             return 0;
+//         }
+//         ...
+//     }
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_cancel_is_seen_by_all_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn test_current_token_belongs_to_the_thread() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let seen = thread::spawn(move || {
+            token.make_current();
+            CancellationToken::current().is_cancelled()
+        })
+        .join()
+        .unwrap();
+
+        assert!(seen);
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<CancellationToken> = RefCell::new(CancellationToken::new());
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// The token of the part running on this thread. Outside of the runner
+    /// this is a token that nobody cancels.
+    pub fn current() -> CancellationToken {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Make this the token returned by `current` on this thread
+    pub fn make_current(&self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.clone());
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
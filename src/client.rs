@@ -0,0 +1,230 @@
+// Talking to the Advent of Code website, to download the puzzle inputs and to
+// submit answers.
+
+use crate::config::Config;
+use crate::{Answer, AocError, AocErrorType, Part};
+use regex::Regex;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_answer() {
+        let page =
+            "<article><p>That's the right answer!  You are <span>one gold star</span> closer \
+                    to finding the Chief Historian.</p></article>";
+        assert_eq!(Outcome::parse(page), Outcome::Correct);
+    }
+
+    #[test]
+    fn test_wrong_answers() {
+        let page = "<article><p>That's not the right answer; your answer is too high.  If you're \
+                    stuck, make sure you're using the full input data. Please wait one minute \
+                    before trying again.</p></article>";
+        assert_eq!(Outcome::parse(page), Outcome::TooHigh);
+        assert_eq!(
+            Outcome::parse(&page.replace("too high", "too low")),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer.  If you're stuck...</p>"),
+            Outcome::Wrong
+        );
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let page = "<article><p>You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again.  You have 4m 41s left to wait. \
+                    </p></article>";
+        assert_eq!(Outcome::parse(page), Outcome::Wait(281));
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 35s left to wait."),
+            Outcome::Wait(35)
+        );
+    }
+
+    #[test]
+    fn test_already_solved_and_unknown() {
+        let page = "<article><p>You don't seem to be solving the right level.  Did you already \
+                    complete it?</p></article>";
+        assert_eq!(Outcome::parse(page), Outcome::AlreadySolved);
+        assert_eq!(
+            Outcome::parse("<article><p>Something else</p></article>"),
+            Outcome::Unknown("Something else".to_string())
+        );
+    }
+}
+
+pub const YEAR: u32 = 2024;
+
+/// Identifies us to the website, as asked for by its automation guidelines
+pub const USER_AGENT: &str = concat!(
+    "aoc2024/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/Jokymon/AoC-2024)"
+);
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    /// Fails when the configuration doesn't contain a session cookie
+    pub fn new(config: &Config) -> Result<AocClient, AocError> {
+        let session = config
+            .session
+            .clone()
+            .ok_or(AocError::new(AocErrorType::MissingSession))?;
+        Ok(AocClient {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config.base_url.clone(),
+            session,
+        })
+    }
+
+    pub fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        let failed = |reason: String| AocError::new(AocErrorType::RequestFailed(reason));
+
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| failed(e.to_string()))?
+            .into_string()
+            .map_err(|e| failed(e.to_string()))
+    }
+
+    /// Download the puzzle input of a day, without looking at any cache
+    pub fn download_input(&self, day: u32) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Submit the answer of one part and tell what the website thinks of it
+    pub fn submit_answer(
+        &self,
+        day: u32,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Outcome, AocError> {
+        let failed = |reason: String| AocError::new(AocErrorType::RequestFailed(reason));
+
+        let page = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(|e| failed(e.to_string()))?
+            .into_string()
+            .map_err(|e| failed(e.to_string()))?;
+        Ok(Outcome::parse(&page))
+    }
+}
+
+/// The verdict of the website on a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction
+    Wrong,
+    /// Submitted too soon after the last answer, retry after that many seconds
+    Wait(u64),
+    AlreadySolved,
+    /// A response we don't understand, with the text of its main article
+    Unknown(String),
+}
+
+impl Outcome {
+    pub fn parse(page: &str) -> Outcome {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let seconds = wait
+                .captures(page)
+                .map(|c| {
+                    let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    minutes * 60 + c[2].parse::<u64>().unwrap()
+                })
+                .unwrap_or(60);
+            Outcome::Wait(seconds)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+            let tags = Regex::new(r"<[^>]*>").unwrap();
+            let text = article
+                .captures(page)
+                .map_or(page, |c| c.get(1).unwrap().as_str());
+            Outcome::Unknown(tags.replace_all(text, "").trim().to_string())
+        }
+    }
+
+    /// Whether the website judged the answer itself, as opposed to refusing
+    /// to look at it
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(seconds) => write!(f, "wait {} seconds", seconds),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Make sure the input of a day is stored at `cache`, downloading it when it
+/// isn't there yet. Returns whether the input had to be downloaded.
+pub fn fetch_input(client: &AocClient, day: u32, cache: &Path) -> Result<bool, AocError> {
+    if cache.exists() {
+        return Ok(false);
+    }
+
+    let input = client.download_input(day)?;
+    let write_failed = |e: std::io::Error| {
+        AocError::new(AocErrorType::WriteFailed(format!(
+            "{}: {}",
+            cache.display(),
+            e
+        )))
+    };
+    if let Some(directory) = cache.parent() {
+        fs::create_dir_all(directory).map_err(write_failed)?;
+    }
+    fs::write(cache, input).map_err(write_failed)?;
+    Ok(true)
+}
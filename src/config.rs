@@ -0,0 +1,113 @@
+// Settings for talking to the Advent of Code website. They are read from an
+// optional `aoc.toml` in the working directory:
+//
+//     session = "53616c7465645f5f..."
+//     base_url = "https://adventofcode.com"
+//
+// The environment variables AOC_SESSION and AOC_BASE_URL take precedence over
+// the file.
+
+use crate::{AocError, AocErrorType};
+use std::env;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use toml::Table;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config =
+            Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080/\"").unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::parse("").unwrap();
+
+        assert_eq!(config.session, None);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Config::parse("session = 42").is_err());
+        assert!(Config::parse("sesion = \"abc\"").is_err());
+    }
+}
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    /// Where the website lives, without a trailing slash
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(source: &str) -> Result<Config, AocError> {
+        let invalid = |reason: String| AocError::new(AocErrorType::InvalidConfig(reason));
+
+        let table: Table = source
+            .parse()
+            .map_err(|e: toml::de::Error| invalid(e.to_string()))?;
+        let mut config = Config::default();
+        for (key, value) in table {
+            let value = value
+                .as_str()
+                .ok_or(invalid(format!("'{}' must be a string", key)))?
+                .to_string();
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(invalid(format!("unknown key '{}'", key))),
+            }
+        }
+        Ok(config.normalized())
+    }
+
+    /// Read `aoc.toml`, if there is one, and apply the environment variables
+    pub fn load() -> Result<Config, AocError> {
+        let mut config = match read_to_string(CONFIG_PATH) {
+            Ok(source) => Config::parse(&source)?,
+            Err(error) if error.kind() == ErrorKind::NotFound => Config::default(),
+            Err(error) => {
+                return Err(AocError::new(AocErrorType::InvalidConfig(
+                    error.to_string(),
+                )))
+            }
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config.normalized())
+    }
+
+    fn normalized(mut self) -> Config {
+        self.base_url = self.base_url.trim_end_matches('/').to_string();
+        if let Some(session) = &self.session {
+            self.session = Some(session.trim().to_string());
+        }
+        self
+    }
+}
@@ -0,0 +1,57 @@
+//! Day 1: Historian Hysteria
+
+use crate::parse::ParseError;
+use crate::{Answer, AocError, Registry, Solution, TableReader};
+use itertools::Itertools;
+
+/// The location ids of the left and the right list
+pub type LocationIds = [Vec<i32>; 2];
+
+pub fn parse_input(challenge_input: &str) -> Result<LocationIds, ParseError> {
+    TableReader::new().columns(challenge_input)
+}
+
+pub fn challenge([left, right]: &LocationIds) -> i32 {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    left.sort();
+    right.sort();
+
+    left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum()
+}
+
+pub fn challenge2([left, right]: &LocationIds) -> i32 {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    left.sort();
+    right.sort();
+
+    let freqs = right.iter().counts();
+
+    left.iter()
+        .map(|l| l * *freqs.get(l).unwrap_or(&0) as i32)
+        .sum()
+}
+
+#[derive(Clone)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = LocationIds;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, location_ids: &Self::Input) -> Answer {
+        challenge(location_ids).into()
+    }
+
+    fn part2(&self, location_ids: &Self::Input) -> Answer {
+        challenge2(location_ids).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(1, Day1);
+}
@@ -0,0 +1,139 @@
+//! Day 10: Hoof It
+
+use crate::parse::ParseError;
+use crate::tile::Cell;
+use crate::{Answer, AocError, Field, Location, Neighborhood, Registry, Solution};
+
+#[derive(Debug, PartialEq)]
+struct Explorer {
+    id: i32,
+    x: i32,
+    y: i32,
+    height: u32,
+}
+
+// Basic idea: we create one "explorer" (as in real life person) or group, that starts from one of the trail heads.
+// Such an explorer will then go and try to find a summit and split up into more groups at every point where there
+// are multiple choices to continue. This function reads the heights of the map and extracts the trail heads.
+fn parse_map(input: &str) -> Result<(Field<u32>, Vec<Location>), ParseError> {
+    let (map, markers) = Field::parse(input, |ch| match ch {
+        '0' => Some(Cell::Marker(0)),
+        _ => ch.to_digit(10).map(Cell::Tile),
+    })?;
+    Ok((map, markers.all('0').to_vec()))
+}
+
+fn get_explorers(trailheads: &[Location]) -> Vec<Explorer> {
+    trailheads
+        .iter()
+        .enumerate()
+        .map(|(id, trailhead)| Explorer {
+            id: id as i32,
+            x: trailhead.column,
+            y: trailhead.row,
+            height: 0,
+        })
+        .collect()
+}
+
+fn next_steps(map: &Field<u32>, explorer: &Explorer) -> Vec<Explorer> {
+    let mut new_explorers: Vec<_> = vec![];
+    // Find next step in the neighbourhood of this explorer
+    // For every possible next step, we create a new explorer
+    // REMARK: Initially I iterated with dx, dy from -1 to 1. However this is
+    // wrong since that way we would also walk diagonally
+    let location = Location {
+        column: explorer.x,
+        row: explorer.y,
+    };
+    for (next, _, height) in map.actual_neighbors(&location, Neighborhood::VonNeumann) {
+        if height == explorer.height + 1 {
+            new_explorers.push(Explorer {
+                id: explorer.id,
+                x: next.column,
+                y: next.row,
+                height,
+            });
+        }
+    }
+    new_explorers
+}
+
+pub fn challenge1(map: &Field<u32>, trailheads: &[Location]) -> i32 {
+    let mut explorers = get_explorers(trailheads);
+    let mut trailhead_summit: Vec<Explorer> = vec![];
+    let mut total_score = 0;
+
+    // We try to follow every explorer to a summit
+    while let Some(mut front) = explorers.pop() {
+        let mut new_explorers = next_steps(map, &front);
+        while !new_explorers.is_empty() && front.height != 9 {
+            front = new_explorers.remove(0);
+
+            // If we reach a split, we create a new explorer and must follow one
+            // of the explorers to the summit
+            for explorer in new_explorers {
+                explorers.push(explorer);
+            }
+            new_explorers = next_steps(map, &front);
+        }
+
+        // Once we actually reach the top, we check if we already reached this
+        // same summit from same trailhead
+        if front.height == 9 && !trailhead_summit.contains(&front) {
+            trailhead_summit.push(front);
+            total_score += 1;
+        }
+    }
+    total_score
+}
+
+pub fn challenge2(map: &Field<u32>, trailheads: &[Location]) -> i32 {
+    let mut explorers = get_explorers(trailheads);
+    let mut total_score = 0;
+
+    // We try to follow every explorer to a summit
+    while let Some(mut front) = explorers.pop() {
+        let mut new_explorers = next_steps(map, &front);
+        while !new_explorers.is_empty() && front.height != 9 {
+            front = new_explorers.remove(0);
+
+            // If we reach a split, we create a new explorer and must follow one
+            // of the explorers to the summit
+            for explorer in new_explorers {
+                explorers.push(explorer);
+            }
+            new_explorers = next_steps(map, &front);
+        }
+
+        // Once we actually reach the top, we check if we already reached this
+        // same summit from same trailhead
+        if front.height == 9 {
+            total_score += 1;
+        }
+    }
+    total_score
+}
+
+#[derive(Clone)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Field<u32>, Vec<Location>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(&self, (topographic_map, trailheads): &Self::Input) -> Answer {
+        challenge1(topographic_map, trailheads).into()
+    }
+
+    fn part2(&self, (topographic_map, trailheads): &Self::Input) -> Answer {
+        challenge2(topographic_map, trailheads).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(10, Day10);
+}
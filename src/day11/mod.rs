@@ -0,0 +1,128 @@
+//! Day 11: Plutonian Pebbles
+
+use crate::params::{positive, unknown_parameter};
+use crate::parse::{parse_lines, ParseError};
+use crate::{Answer, AocError, Registry, SimpleParse, Solution};
+use count_digits::CountDigits;
+use memoize::memoize;
+
+#[memoize]
+fn stones_from_blinks(stone: i64, blinks: i32) -> i64 {
+    if blinks == 0 {
+        return 1;
+    }
+    match stone {
+        0 => stones_from_blinks(1, blinks - 1),
+        stone if stone.count_digits() % 2 == 0 => {
+            let total_digits = stone.count_digits();
+
+            let part1 = stone / i64::pow(10, (total_digits / 2) as u32);
+            let part2 = stone % i64::pow(10, (total_digits / 2) as u32);
+
+            stones_from_blinks(part1, blinks - 1) + stones_from_blinks(part2, blinks - 1)
+        }
+        _ => stones_from_blinks(stone * 2024, blinks - 1),
+    }
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<Vec<i64>, ParseError> {
+    let lines = parse_lines(challenge_input, |line| {
+        line.split_whitespace()
+            .map(str::try_i64)
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok(lines.concat())
+}
+
+fn blink_n_times(stones: &[i64], blinks: i32) -> i64 {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = stones
+            .iter()
+            .flat_map(|stone| match *stone {
+                0 => vec![1],
+                stone if stone.count_digits() % 2 == 0 => {
+                    let total_digits = stone.count_digits();
+                    let part1 = stone / i64::pow(10, (total_digits / 2) as u32);
+                    let part2 = stone % i64::pow(10, (total_digits / 2) as u32);
+                    vec![part1, part2]
+                }
+                _ => vec![*stone * 2024],
+            })
+            .collect();
+    }
+    stones.len() as i64
+}
+
+// I have to admit, I first tried just running the "functional" approach for part 1 again on
+// part 2, but quickly noticed, that this might not go well. After filling the RAM up to several
+// gigabytes and running for several minutes, the program finally crashed.
+// So I knew, I needed a different approach and found the suggestion of memoization and handling
+// entries individually on Reddit. The concept is thus based on that suggestion, but the
+// implementation is mine ;-)
+fn blink_n_times_memory_save(stones: &[i64], blinks: i32) -> i64 {
+    stones
+        .iter()
+        .map(|stone| { stones_from_blinks }(*stone, blinks))
+        .sum()
+}
+
+pub fn challenge1(stones: &[i64], blinks: i32) -> i64 {
+    blink_n_times(stones, blinks)
+}
+
+pub fn challenge2(stones: &[i64], blinks: i32) -> i64 {
+    blink_n_times_memory_save(stones, blinks)
+}
+
+/// How often the stones change in each part
+#[derive(Clone)]
+pub struct Day11 {
+    pub blinks_part1: i32,
+    pub blinks_part2: i32,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            blinks_part1: 25,
+            blinks_part2: 75,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Answer {
+        challenge1(stones, self.blinks_part1).into()
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Answer {
+        challenge2(stones, self.blinks_part2).into()
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("blinks_part1", self.blinks_part1 as i64),
+            ("blinks_part2", self.blinks_part2 as i64),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: i64) -> Result<(), AocError> {
+        match name {
+            "blinks_part1" => self.blinks_part1 = positive(name, value)?,
+            "blinks_part2" => self.blinks_part2 = positive(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(11, Day11::default());
+}
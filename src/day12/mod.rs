@@ -0,0 +1,120 @@
+//! Day 12: Garden Groups
+
+use crate::parse::ParseError;
+use crate::tile::{parse_tiles, Tile};
+use crate::{Answer, AocError, Field, Location, Neighborhood, Registry, Solution};
+
+#[derive(Tile, PartialEq, Clone, Copy, Debug)]
+pub struct Plot {
+    #[tile(char)]
+    pub plant_type: char,
+    pub assigned: bool, // marker, to indicate whether this plot was assigned to a region
+}
+
+#[derive(PartialEq, Debug)]
+struct Region {
+    fences: usize,
+    area: usize,
+}
+
+pub type Garden = Field<Plot>;
+
+fn collect_region(garden: &mut Garden, regions: &mut Vec<Region>, position: &Location) {
+    let mut visited_positions: Vec<Location> = vec![];
+    let mut search_front: Vec<Location> = vec![*position];
+
+    let mut new_region = Region { area: 0, fences: 1 };
+    let plant_type = garden.at(position).unwrap().plant_type;
+
+    while let Some(position) = search_front.pop() {
+        new_region.area += 1;
+        visited_positions.push(position);
+        garden.at_mut(&position).unwrap().assigned = true;
+
+        for (location, _, neighbor) in garden.actual_neighbors(&position, Neighborhood::VonNeumann)
+        {
+            if visited_positions.contains(&location) || search_front.contains(&location) {
+                // !! if the neighbor is already in the search front, we can also skip it
+                continue;
+            }
+
+            if neighbor.assigned {
+                // This neighbor already belongs to a region
+                continue;
+            }
+
+            if neighbor.plant_type == plant_type {
+                search_front.push(location);
+            }
+        }
+    }
+
+    // Go through all the plots of the previousy collected region
+    new_region.fences = visited_positions
+        .iter()
+        .map(|position| {
+            // Go through all the neighbors of one region and find those neighbors, that have
+            // a different plant type or aren't even on the map
+            garden
+                .all_neighbors(position, Neighborhood::VonNeumann)
+                .filter(|(_location, _, maybe_plot)| {
+                    if let Some(plot) = maybe_plot {
+                        plot.plant_type != plant_type
+                    } else {
+                        true
+                    }
+                })
+                .count()
+        })
+        .sum();
+
+    regions.push(new_region);
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<Garden, ParseError> {
+    let (garden, _) = parse_tiles(challenge_input.trim())?;
+    Ok(garden)
+}
+
+pub fn challenge1(garden: &Garden) -> i64 {
+    let mut garden = garden.clone();
+
+    let mut regions: Vec<Region> = vec![];
+    for location in garden.locations() {
+        if !garden[location].assigned {
+            collect_region(&mut garden, &mut regions, &location);
+        }
+        // Otherwise the plot is already assigned
+    }
+    regions
+        .iter()
+        .map(|region| (region.area * region.fences) as i64)
+        .sum()
+}
+
+pub fn challenge2(_garden: &Garden) -> i64 {
+    42
+}
+
+#[derive(Clone)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, garden: &Self::Input) -> Answer {
+        challenge1(garden).into()
+    }
+
+    fn part2(&self, garden: &Self::Input) -> Answer {
+        challenge2(garden).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(12, Day12);
+}
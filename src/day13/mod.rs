@@ -0,0 +1,168 @@
+//! Day 13: Claw Contraption
+
+use crate::params::{in_range, unknown_parameter};
+use crate::parse::{
+    literal, map, optional, pair, parse_line, preceded, signed_int, terminated, ParseError, Parser,
+};
+use crate::{Answer, AocError, Position, Registry, Solution};
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    pub button_a: Position,
+    pub button_b: Position,
+    pub prize: Position,
+}
+
+// `X+94, Y+34` of a button or `X=8400, Y=5400` of a prize. The `+` of a
+// button is simply taken as the sign of the number.
+fn coordinates() -> impl Parser<Position> {
+    let coordinate =
+        |axis: &'static str| preceded(pair(literal(axis), optional(literal("="))), signed_int());
+    map(
+        pair(terminated(coordinate("X"), literal(", ")), coordinate("Y")),
+        |(x, y)| Position { x, y },
+    )
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let mut machines: Vec<ClawMachine> = vec![];
+
+    let mut button_a = Position { x: 0, y: 0 };
+    let mut button_b = Position { x: 0, y: 0 };
+    let mut prize;
+
+    for (index, line) in input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+    {
+        let position_after = |prefix: &'static str| {
+            parse_line(line, preceded(literal(prefix), coordinates()))
+                .map_err(|error| error.on_line(index + 1, line))
+        };
+        match line.split_once(":").map(|(name, _)| name) {
+            Some("Button A") => {
+                button_a = position_after("Button A: ")?;
+            }
+            Some("Button B") => {
+                button_b = position_after("Button B: ")?;
+            }
+            Some("Prize") => {
+                prize = position_after("Prize: ")?;
+                machines.push(ClawMachine {
+                    button_a,
+                    button_b,
+                    prize,
+                });
+            }
+            _ => {
+                return Err(
+                    ParseError::new(line, 0, line, "expected a button or a prize")
+                        .on_line(index + 1, line),
+                )
+            }
+        }
+    }
+
+    Ok(machines)
+}
+
+/// Calculate the cost for winning a prize with the given claw machine. The
+/// function returns a `None` when there is no combination to win a prize.
+/// When a prize can be won, the function returns the amount of needed tokens.
+pub fn solve_claw_machine(clawmachine: &ClawMachine) -> Option<i64> {
+    let det_ab = clawmachine.button_a.x * clawmachine.button_b.y
+        - clawmachine.button_a.y * clawmachine.button_b.x;
+    if det_ab == 0 {
+        panic!("No clawmachines with Det 0 are currently expected");
+    }
+
+    // Using Cramers rule to calculate the solution of the equation system
+    let det_ap =
+        clawmachine.button_a.x * clawmachine.prize.y - clawmachine.button_a.y * clawmachine.prize.x;
+    let det_pb =
+        clawmachine.prize.x * clawmachine.button_b.y - clawmachine.prize.y * clawmachine.button_b.x;
+
+    if det_ap % det_ab != 0 {
+        return None;
+    }
+    if det_pb % det_ab != 0 {
+        return None;
+    }
+
+    let moves_a = det_pb / det_ab;
+    let moves_b = det_ap / det_ab;
+
+    Some(moves_a * 3 + moves_b)
+}
+
+pub fn challenge1(claw_machines: &[ClawMachine]) -> i64 {
+    claw_machines.iter().flat_map(solve_claw_machine).sum()
+}
+
+pub fn challenge2(claw_machines: &[ClawMachine], prize_offset: i64) -> i64 {
+    claw_machines
+        .iter()
+        .flat_map(|machine| {
+            solve_claw_machine(&ClawMachine {
+                button_a: machine.button_a,
+                button_b: machine.button_b,
+                prize: Position {
+                    x: machine.prize.x + prize_offset,
+                    y: machine.prize.y + prize_offset,
+                },
+            })
+        })
+        .sum()
+}
+
+// The buttons of the puzzle move less than 100 and the prizes stay below
+// 100000, so with offsets up to this the products of Cramer's rule stay far
+// below `i64::MAX`
+const MAX_PRIZE_OFFSET: i64 = 1_000_000_000_000_000;
+
+/// In part 2 the prizes are `prize_offset` further away in both directions
+#[derive(Clone)]
+pub struct Day13 {
+    pub prize_offset: i64,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Day13 {
+            prize_offset: 10000000000000,
+        }
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, claw_machines: &Self::Input) -> Answer {
+        challenge1(claw_machines).into()
+    }
+
+    fn part2(&self, claw_machines: &Self::Input) -> Answer {
+        challenge2(claw_machines, self.prize_offset).into()
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        vec![("prize_offset", self.prize_offset)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: i64) -> Result<(), AocError> {
+        match name {
+            "prize_offset" => self.prize_offset = in_range(name, value, 0..=MAX_PRIZE_OFFSET)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(13, Day13::default());
+}
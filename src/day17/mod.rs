@@ -0,0 +1,176 @@
+//! Day 17: Chronospatial Computer
+
+use crate::parse::{literal, parse_line, preceded, separated, signed_int, ParseError};
+use crate::{Answer, AocError, InputReader, Registry, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub opcode: i32,
+    pub operand: i32,
+}
+
+/// The 3-bit computer with its three registers and the loaded program
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub register_a: i32,
+    pub register_b: i32,
+    pub register_c: i32,
+
+    pub instruction_pointer: i32,
+
+    pub program: Vec<Instruction>,
+}
+
+pub const ADV: i32 = 0;
+pub const BXL: i32 = 1;
+pub const BST: i32 = 2;
+pub const JNZ: i32 = 3;
+pub const BXC: i32 = 4;
+pub const OUT: i32 = 5;
+pub const BDV: i32 = 6;
+pub const CDV: i32 = 7;
+
+pub fn mnemonic(opcode: i32) -> String {
+    match opcode {
+        ADV => "ADV",
+        BXL => "BXL",
+        BST => "BST",
+        JNZ => "JNZ",
+        BXC => "BXC",
+        OUT => "OUT",
+        BDV => "BDV",
+        CDV => "CDV",
+        _ => "ILGL!",
+    }
+    .to_string()
+}
+
+impl Computer {
+    // determine the actual value of a "combo" operand
+    fn combo(&self, operand: i32) -> i32 {
+        match operand {
+            0..=3 => operand,
+            4 => self.register_a,
+            5 => self.register_b,
+            6 => self.register_c,
+            _ => panic!("Illegal combo operand {}", operand),
+        }
+    }
+
+    /// Run the program until the instruction pointer leaves the program and
+    /// return the produced outputs as comma separated list.
+    pub fn run_to_completion(&mut self) -> String {
+        let mut outputs: Vec<i32> = vec![];
+
+        while self.instruction_pointer < self.program.len() as i32 {
+            let Instruction { opcode, operand } = self.program[self.instruction_pointer as usize];
+            // println!(
+            //     "IP: {}, opcode={}, operand={}, A={}, B={}, C={}",
+            //     self.instruction_pointer,
+            //     mnemonic(opcode),
+            //     operand,
+            //     self.register_a,
+            //     self.register_b,
+            //     self.register_c
+            // );
+            self.instruction_pointer += 1;
+
+            match opcode {
+                ADV => self.register_a >>= self.combo(operand) as u32,
+                BXL => self.register_b ^= operand,
+                BST => self.register_b = self.combo(operand) % 8,
+                JNZ => {
+                    if self.register_a != 0 {
+                        self.instruction_pointer = operand / 2 // our instructions are index together with the operands
+                    }
+                }
+                BXC => self.register_b ^= self.register_c,
+                OUT => outputs.push(self.combo(operand) % 8),
+                BDV => self.register_b = self.register_a / i32::pow(2, self.combo(operand) as u32),
+                CDV => self.register_c = self.register_a / i32::pow(2, self.combo(operand) as u32),
+                _ => panic!(
+                    "Illegal opcode {} at IP={}",
+                    opcode,
+                    self.instruction_pointer - 1
+                ),
+            }
+        }
+        outputs.into_iter().join(",")
+    }
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<Computer, ParseError> {
+    let mut computer = Computer {
+        register_a: 0,
+        register_b: 0,
+        register_c: 0,
+        instruction_pointer: 0,
+        program: vec![],
+    };
+    let reader = InputReader::new(challenge_input);
+    let (registers, program) = reader.two_sections()?;
+
+    for (index, line) in registers.lines().enumerate() {
+        let value = |name: &'static str| {
+            parse_line(line, preceded(literal(name), signed_int()))
+                .map_err(|error| error.on_line(index + 1, line))
+        };
+        if line.starts_with("Register A") {
+            computer.register_a = value("Register A: ")?;
+        } else if line.starts_with("Register B") {
+            computer.register_b = value("Register B: ")?;
+        } else if line.starts_with("Register C") {
+            computer.register_c = value("Register C: ")?;
+        }
+    }
+
+    // The program follows the registers after an empty line
+    let program_code: Vec<i32> = parse_line(
+        program,
+        preceded(literal("Program: "), separated(signed_int(), literal(","))),
+    )
+    .map_err(|error| error.on_line(registers.lines().count() + 2, program))?;
+
+    computer.program = program_code
+        .iter()
+        .step_by(2)
+        .zip(program_code.iter().skip(1).step_by(2))
+        .map(|(&opcode, &operand)| Instruction { opcode, operand })
+        .collect();
+
+    Ok(computer)
+}
+
+pub fn challenge1(computer: &Computer) -> String {
+    let mut computer = computer.clone();
+
+    computer.run_to_completion()
+}
+
+pub fn challenge2(_computer: &Computer) -> i64 {
+    42
+}
+
+#[derive(Clone)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, computer: &Self::Input) -> Answer {
+        challenge1(computer).into()
+    }
+
+    fn part2(&self, computer: &Self::Input) -> Answer {
+        challenge2(computer).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(17, Day17);
+}
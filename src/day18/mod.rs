@@ -0,0 +1,176 @@
+//! Day 18: RAM Run
+
+use crate::cancel::CancellationToken;
+use crate::params::{non_negative, positive, unknown_parameter};
+use crate::parse::{parse_lines, ParseError};
+use crate::{Answer, AocError, Field, Location, Neighborhood, Registry, SimpleParse, Solution};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_outside_of_the_memory_space() {
+        let error = parse_input("1,2\n6,7\n", 7).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "expected a location within the 7x7 memory space");
+        assert_eq!(parse_input("1,2\n6,0\n", 7).unwrap().len(), 2);
+    }
+}
+
+/// The locations of the falling bytes, which must all be within the memory
+/// space of `gridsize` x `gridsize` tiles
+pub fn parse_input(challenge_input: &str, gridsize: i32) -> Result<Vec<Location>, ParseError> {
+    parse_lines(challenge_input, |line| {
+        let (column, row) = line.split_once(",").ok_or_else(|| {
+            ParseError::new(line, 0, line, "expected two numbers separated by ','")
+        })?;
+        let location = Location {
+            column: column.try_i32()?,
+            row: row.try_i32()?,
+        };
+        if !(0..gridsize).contains(&location.column) || !(0..gridsize).contains(&location.row) {
+            let message = format!(
+                "expected a location within the {}x{} memory space",
+                gridsize, gridsize
+            );
+            return Err(ParseError::new(line, 0, line, &message));
+        }
+        Ok(location)
+    })
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum MemoryLocation {
+    Free(i32), // minimum amount of steps to reach this location
+    Corruption,
+}
+type Memory = Field<MemoryLocation>;
+
+pub fn challenge1(falling_bytes_locations: &[Location], gridsize: i32, fallen_bytes: i64) -> i64 {
+    let mut memory_space: Memory = Field::filled(
+        gridsize as usize,
+        gridsize as usize,
+        MemoryLocation::Free(i32::MAX),
+    );
+
+    // Corrupt the memory
+    for location in falling_bytes_locations.iter().take(fallen_bytes as usize) {
+        memory_space.put(location, MemoryLocation::Corruption);
+    }
+
+    // Find a path
+    // The first approach used path finding similar to day16 with walkers. However that quickly
+    // turned into a giant mass of walkers and a long runtime. So this implementation uses a
+    // variant of flood filling the available places with the cost for reaching each space.
+    memory_space.put(&Location{column: 0, row: 0}, MemoryLocation::Free(0));
+    let mut open_fronts: Vec<Location> = vec![Location{ column: 0, row: 0}];
+    while !open_fronts.is_empty() {
+        let front = open_fronts.remove(0);
+
+        let front_cost = match memory_space.at(&front).unwrap() {
+            MemoryLocation::Corruption => i32::MAX,
+            MemoryLocation::Free(cost) => cost,
+        };
+
+        for (neighbor_pos, _) in front.neighbors(Neighborhood::VonNeumann) {
+            if let Some(neighbor_entry) = memory_space.at(&neighbor_pos) {
+                match neighbor_entry {
+                    MemoryLocation::Corruption => continue,
+                    MemoryLocation::Free(neighbor_cost) => {
+                        if front_cost+1 < neighbor_cost {
+                            memory_space.put(&neighbor_pos, MemoryLocation::Free(front_cost+1));
+                            open_fronts.push(neighbor_pos);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(target_location) = memory_space.at(&Location{column: gridsize-1, row: gridsize-1}) {
+        match target_location {
+            MemoryLocation::Corruption => panic!("Target location should not be corrupted"),
+            MemoryLocation::Free(cost) => cost as i64,
+        }
+    } else {
+        panic!("Target location is not available")
+    }
+}
+
+pub fn challenge2(
+    falling_bytes_locations: &[Location],
+    gridsize: i32,
+    fallen_bytes_offset: i64,
+) -> String {
+
+    // WOW, this is a really ugly hack and should be refactored into some nice find/fold
+    // something structure :-(
+    let mut fallen_byte_index = fallen_bytes_offset as usize;
+    let mut cost = challenge1(falling_bytes_locations, gridsize, fallen_byte_index as i64);
+    let token = CancellationToken::current();
+    while cost < i32::MAX as i64 {
+        if token.is_cancelled() {
+            return String::new();
+        }
+        fallen_byte_index += 1;
+        cost = challenge1(falling_bytes_locations, gridsize, fallen_byte_index as i64);
+    }
+
+    let blocking_location = falling_bytes_locations[fallen_byte_index-1];
+    format!("{},{}", blocking_location.column, blocking_location.row)
+}
+
+/// The memory space is `gridsize` x `gridsize` tiles, of which the first
+/// `fallen_bytes` are corrupted before part 1 looks for a path
+#[derive(Clone)]
+pub struct Day18 {
+    pub gridsize: i32,
+    pub fallen_bytes: i64,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Day18 {
+            gridsize: 71,
+            fallen_bytes: 1024,
+        }
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<Location>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input, self.gridsize)?)
+    }
+
+    fn part1(&self, falling_bytes: &Self::Input) -> Answer {
+        challenge1(falling_bytes, self.gridsize, self.fallen_bytes).into()
+    }
+
+    fn part2(&self, falling_bytes: &Self::Input) -> Answer {
+        challenge2(falling_bytes, self.gridsize, self.fallen_bytes).into()
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("gridsize", self.gridsize as i64),
+            ("fallen_bytes", self.fallen_bytes),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: i64) -> Result<(), AocError> {
+        match name {
+            "gridsize" => self.gridsize = positive(name, value)?,
+            "fallen_bytes" => self.fallen_bytes = non_negative(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(18, Day18::default());
+}
@@ -0,0 +1,57 @@
+//! Day 19: Linen Layout
+
+use crate::parse::ParseError;
+use crate::{Answer, AocError, InputReader, Registry, Solution};
+use itertools::Itertools;
+use regex::Regex;
+
+pub type ChallengeInput = (Regex, Vec<String>);
+
+pub fn parse_input(input: &str) -> Result<ChallengeInput, ParseError> {
+    let reader = InputReader::new(input);
+    let (available_patterns, desired_designs) = reader.two_sections()?;
+
+    let regex_string = format!("^({})+$", available_patterns.split(",").map(str::trim).join("|"));
+
+    Ok((
+        Regex::new(&regex_string).unwrap(),
+        desired_designs
+            .split_whitespace()
+            .map(str::to_string)
+            .collect(),
+    ))
+}
+
+pub fn challenge1((towel_rules, designs): &ChallengeInput) -> i64 {
+
+    designs.iter().filter(|design| {
+        towel_rules.is_match(design)
+    }).count() as i64
+}
+
+pub fn challenge2(_input: &ChallengeInput) -> i64 {
+    42
+}
+
+#[derive(Clone)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = ChallengeInput;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        challenge1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        challenge2(input).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(19, Day19);
+}
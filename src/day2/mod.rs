@@ -0,0 +1,127 @@
+//! Day 2: Red-Nosed Reports
+
+use crate::parse::ParseError;
+use crate::{Answer, AocError, Registry, Solution, TableReader};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum LevelsAre {
+    Unknown,
+    Increasing,
+    Decreasing,
+    Unsafe,
+}
+
+fn rating_from_pair(first: i32, second: i32) -> LevelsAre {
+    let diff = second - first;
+    if (diff.abs() < 1) || (diff.abs() > 3) {
+        LevelsAre::Unsafe
+    } else if diff < 0 {
+        LevelsAre::Decreasing
+    } else {
+        LevelsAre::Increasing
+    }
+}
+
+struct Rating {
+    is_safe: bool,
+    failure_index: usize,
+}
+
+fn rating_from_readings(readings: &[i32]) -> Rating {
+    let mut final_rating: LevelsAre = LevelsAre::Unknown;
+    let mut failure_index: usize = 0;
+    let mut is_safe = true;
+
+    let reading_iter = readings.iter();
+    for (idx, (first, second)) in reading_iter.clone().zip(reading_iter.skip(1)).enumerate() {
+        let rating = rating_from_pair(*first, *second);
+        if final_rating == LevelsAre::Unknown {
+            final_rating = rating;
+        }
+
+        if (final_rating != rating) || (rating == LevelsAre::Unsafe) {
+            failure_index = idx;
+            is_safe = false;
+            break;
+        }
+    }
+    Rating {
+        is_safe,
+        failure_index,
+    }
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    TableReader::new().rows(challenge_input)
+}
+
+pub fn challenge(reports: &[Vec<i32>]) -> i32 {
+    let safe_counter = reports
+        .iter()
+        .filter(|readings| rating_from_readings(readings).is_safe)
+        .count();
+
+    safe_counter as i32
+}
+
+pub fn challenge2(reports: &[Vec<i32>]) -> i32 {
+    let mut safe_counter = 0;
+
+    for readings in reports {
+        let Rating {
+            is_safe,
+            failure_index,
+        } = rating_from_readings(readings);
+
+        if is_safe {
+            safe_counter += 1;
+        } else {
+            let lower_bound = if failure_index == 0 {
+                0
+            } else {
+                failure_index - 1
+            };
+            for remove_index in lower_bound..(failure_index + 2) {
+                if remove_index >= readings.len() {
+                    continue;
+                }
+
+                let mut dampened_readings = readings.to_vec();
+                dampened_readings.remove(remove_index);
+
+                let Rating {
+                    is_safe: is_safe2,
+                    failure_index: _,
+                } = rating_from_readings(&dampened_readings);
+                if is_safe2 {
+                    safe_counter += 1;
+                    break;
+                }
+            }
+        }
+    }
+    safe_counter
+}
+
+#[derive(Clone)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Answer {
+        challenge(reports).into()
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Answer {
+        challenge2(reports).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(2, Day2);
+}
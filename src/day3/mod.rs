@@ -0,0 +1,66 @@
+//! Day 3: Mull It Over
+
+use crate::{Answer, AocError, Registry, SimpleParse, Solution};
+use regex::Regex;
+
+pub fn challenge(challenge_input: &str) -> i32 {
+    let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
+    re.captures_iter(challenge_input)
+        .map(|cap| {
+            let (_, [left, right]) = cap.extract();
+            let left_num = left.get_i32();
+            let right_num = right.get_i32();
+            left_num * right_num
+        })
+        .sum()
+}
+
+pub fn challenge2(challenge_input: &str) -> i32 {
+    let re = Regex::new(r"(?<op>mul|do|don't)\(((?<left>[0-9]+),(?<right>[0-9]+))?\)").unwrap();
+    let mut is_enabled = true;
+    re.captures_iter(challenge_input)
+        .map(|caps| {
+            let op = caps.name("op").unwrap().as_str();
+
+            let val;
+            (is_enabled, val) = match op {
+                "do" => (true, 0),
+                "don't" => (false, 0),
+                "mul" => {
+                    if is_enabled {
+                        let left = caps.name("left").unwrap().as_str().get_i32();
+                        let right = caps.name("right").unwrap().as_str().get_i32();
+                        (true, left * right)
+                    } else {
+                        (false, 0)
+                    }
+                }
+                _ => (is_enabled, 0),
+            };
+            val
+        })
+        .sum()
+}
+
+#[derive(Clone)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, memory: &Self::Input) -> Answer {
+        challenge(memory).into()
+    }
+
+    fn part2(&self, memory: &Self::Input) -> Answer {
+        challenge2(memory).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(3, Day3);
+}
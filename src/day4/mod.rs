@@ -0,0 +1,90 @@
+//! Day 4: Ceres Search
+
+use crate::tile::Cell;
+use crate::{Answer, AocError, Direction, Field, FieldLine, Location, Registry, Solution};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_words_in_all_directions() {
+        let word_search = Field::new(vec![
+            "XMASAMX".chars().collect(),
+            "MM.....".chars().collect(),
+            "A.A....".chars().collect(),
+            "S..S...".chars().collect(),
+        ]);
+
+        assert_eq!(count_xmas(&word_search), 4);
+    }
+}
+
+// How often "XMAS" can be read forwards or backwards along the given lines
+fn count_words<'a>(lines: impl Iterator<Item = FieldLine<'a, char>>) -> usize {
+    lines
+        .map(|line| {
+            let text: String = line.into_iter().map(|(_, ch)| *ch).collect();
+            text.matches("XMAS").count() + text.matches("SAMX").count()
+        })
+        .sum()
+}
+
+fn count_xmas(word_search: &Field<char>) -> usize {
+    count_words(word_search.row_lines())
+        + count_words(word_search.column_lines())
+        + count_words(word_search.main_diagonals())
+        + count_words(word_search.anti_diagonals())
+}
+
+pub fn challenge1(word_search: &Field<char>) -> i32 {
+    count_xmas(word_search) as i32
+}
+
+pub fn challenge2(word_search: &Field<char>) -> i32 {
+    let corner = |location: Location, direction| {
+        word_search
+            .at(&location.in_direction(direction))
+            .unwrap_or('.')
+    };
+    let is_mas = |a: char, b: char| matches!((a, b), ('M', 'S') | ('S', 'M'));
+
+    let mut mas_count = 0;
+    for (location, letter) in word_search.each_location() {
+        if letter == 'A' {
+            let up_left = corner(location, Direction::UpLeft);
+            let up_right = corner(location, Direction::UpRight);
+            let down_right = corner(location, Direction::DownRight);
+            let down_left = corner(location, Direction::DownLeft);
+
+            if is_mas(up_left, down_right) && is_mas(up_right, down_left) {
+                mas_count += 1;
+            }
+        }
+    }
+    mas_count
+}
+
+#[derive(Clone)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Field<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let (word_search, _) = Field::parse(input, |ch| Some(Cell::Tile(ch)))?;
+        Ok(word_search)
+    }
+
+    fn part1(&self, word_search: &Self::Input) -> Answer {
+        challenge1(word_search).into()
+    }
+
+    fn part2(&self, word_search: &Self::Input) -> Answer {
+        challenge2(word_search).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(4, Day4);
+}
@@ -0,0 +1,112 @@
+//! Day 5: Print Queue
+
+use crate::parse::ParseError;
+use crate::{Answer, AocError, InputReader, Registry, Solution, TableReader};
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pages_that_satisfy_the_rules() {
+        let rules = vec![(1, 3), (4, 5)];
+        let page_update = vec![1, 3, 4, 5];
+
+        assert!(update_satisfies_rules(&page_update, &rules));
+    }
+
+    #[test]
+    fn test_pages_that_violate_the_rules() {
+        let rules = vec![(1, 3), (4, 5)];
+        let page_update = vec![1, 5, 4, 3];
+
+        assert!(!update_satisfies_rules(&page_update, &rules));
+    }
+}
+
+pub fn update_satisfies_rules(update: &[i32], rules: &[(i32, i32)]) -> bool {
+    for first_index in 0..update.len() {
+        for second_index in first_index + 1..update.len() {
+            // If the reversed order of the numbers is member of the rules,
+            // then they violate the rules
+            if rules.contains(&(update[second_index], update[first_index])) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub struct ChallengeInput {
+    pub rules: Vec<(i32, i32)>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<ChallengeInput, ParseError> {
+    let reader = InputReader::new(challenge_input);
+    let (rules, updates) = reader.two_sections()?;
+
+    let rules = TableReader::new().delimiter('|').tuples(rules)?;
+    let updates = TableReader::new().delimiter(',').rows(updates)?;
+
+    Ok(ChallengeInput { rules, updates })
+}
+
+pub fn challenge1(input: &ChallengeInput) -> i32 {
+    let sum = input
+        .updates
+        .iter()
+        .filter(|update| update_satisfies_rules(update, &input.rules))
+        .map(|update| update.get(update.len() / 2).unwrap())
+        .sum();
+
+    sum
+}
+
+pub fn challenge2(input: &ChallengeInput) -> i32 {
+    let sum = input.updates.iter().fold(0, |accu, page_update| {
+        if !update_satisfies_rules(page_update, &input.rules) {
+            let mut fixed_update = page_update.clone();
+
+            fixed_update.sort_by(|&x, &y| {
+                if input.rules.contains(&(x, y)) {
+                    Ordering::Less
+                } else if input.rules.contains(&(y, x)) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+
+            accu + fixed_update.get(fixed_update.len() / 2).unwrap()
+        } else {
+            accu
+        }
+    });
+
+    sum
+}
+
+#[derive(Clone)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = ChallengeInput;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer {
+        challenge1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        challenge2(input).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(5, Day5);
+}
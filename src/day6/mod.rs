@@ -0,0 +1,192 @@
+//! Day 6: Guard Gallivant
+
+use crate::cancel::CancellationToken;
+use crate::parse::ParseError;
+use crate::tile::Cell;
+use crate::{Answer, AocError, Field, Location, Registry, Solution};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE_INPUT: &str = include_str!("../../input_data/day6/example1.txt");
+
+    #[test]
+    fn test_walking_produces_a_loop() {
+        let (mut maze, start) = parse_maze(SIMPLE_INPUT).unwrap();
+        maze.put(&Location { column: 3, row: 6 }, '#');
+
+        assert_eq!(walk_the_maze(&maze, start), Walk::Loop);
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Position {
+    row: i32,
+    column: i32,
+}
+
+// The maze with the guard replaced by the floor they stand on, and where they
+// start
+fn parse_maze(input: &str) -> Result<(Field<char>, Position), ParseError> {
+    let (maze, markers) = Field::parse(input, |ch| match ch {
+        '.' | '#' => Some(Cell::Tile(ch)),
+        '^' => Some(Cell::Marker('.')),
+        _ => None,
+    })?;
+    // There should BE a start position, otherwise the quiz is wrong
+    let start = markers.get('^').ok_or_else(|| {
+        ParseError::new(input, input.trim_end().len(), "", "expected the guard '^'")
+    })?;
+    let start = Position {
+        row: start.row,
+        column: start.column,
+    };
+    Ok((maze, start))
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+fn rotate_right(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Right,
+        Direction::Right => Direction::Down,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up,
+    }
+}
+
+fn position_ahead(position: Position, direction: Direction) -> Position {
+    match direction {
+        Direction::Up => Position {
+            column: position.column,
+            row: position.row - 1,
+        },
+        Direction::Down => Position {
+            column: position.column,
+            row: position.row + 1,
+        },
+        Direction::Right => Position {
+            column: position.column + 1,
+            row: position.row,
+        },
+        Direction::Left => Position {
+            column: position.column - 1,
+            row: position.row,
+        },
+    }
+}
+
+fn look_ahead(maze: &Field<char>, position: Position, direction: Direction) -> char {
+    let look_at = position_ahead(position, direction);
+
+    // We use the '%' as character to show that we are off the map
+    maze.at(&Location {
+        column: look_at.column,
+        row: look_at.row,
+    })
+    .unwrap_or('%')
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum Walk {
+    Distance(i32),
+    Loop,
+}
+
+fn walk_the_maze(maze: &Field<char>, start: Position) -> Walk {
+    // let mut visited_places: Vec<Position> = vec![];
+    // Late understanding: We need UNIQUE places
+    let mut visited_places: HashMap<Position, Direction> = HashMap::new();
+    let mut state = Direction::Up;
+    let mut position = start;
+
+    let mut ahead_of_guard = look_ahead(maze, position, state);
+    // see `look_ahead`: We use % as marker for being off the map
+    while ahead_of_guard != '%' {
+        match ahead_of_guard {
+            '#' => {
+                state = rotate_right(state);
+            }
+            '.' => {
+                if let Some(direction) = visited_places.get(&position) {
+                    if *direction == state {
+                        return Walk::Loop;
+                    }
+                } else {
+                    visited_places.insert(position, state);
+                }
+                position = position_ahead(position, state);
+            }
+            _ => {}
+        }
+        ahead_of_guard = look_ahead(maze, position, state);
+    }
+    // TODO: Need to think about this off by one issue
+    Walk::Distance(visited_places.len() as i32 + 1)
+}
+
+pub fn challenge1(maze: &Field<char>, start: Position) -> i32 {
+    match walk_the_maze(maze, start) {
+        Walk::Distance(d) => d,
+        Walk::Loop => panic!("Challenge 1 shouldn't have any loops"),
+    }
+}
+
+pub fn challenge2(maze: &Field<char>, start: Position) -> i32 {
+    let mut possibilites = 0;
+    let mut maze = maze.clone();
+    let token = CancellationToken::current();
+
+    for location in maze.locations() {
+        if token.is_cancelled() {
+            break;
+        }
+        if (Position {
+            row: location.row,
+            column: location.column,
+        }) == start
+        {
+            // The guard would notice an obstacle put right where they stand
+            continue;
+        }
+        // Try the obstacle and take it away again for the next location
+        let floor = maze[location];
+        maze[location] = '#';
+        if walk_the_maze(&maze, start) == Walk::Loop {
+            possibilites += 1;
+        }
+        maze[location] = floor;
+    }
+    possibilites
+}
+
+#[derive(Clone)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Field<char>, Position);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_maze(input)?)
+    }
+
+    fn part1(&self, (maze, start): &Self::Input) -> Answer {
+        challenge1(maze, *start).into()
+    }
+
+    fn part2(&self, (maze, start): &Self::Input) -> Answer {
+        challenge2(maze, *start).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(6, Day6);
+}
@@ -0,0 +1,117 @@
+//! Day 7: Bridge Repair
+
+use crate::parse::{parse_lines, FromLine, ParseError};
+use crate::{Answer, AocError, Registry, Solution};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concatenation() {
+        assert_eq!(concat_i64(34, 234), 34234);
+        assert_eq!(concat_i64(17, 100), 17100);
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)] // the digits are spelled out on purpose
+    fn test_select_digit() {
+        assert_eq!(select_digit(0b0100101, 2, 2), 1);
+        assert_eq!(select_digit(0b0100101, 0, 2), 1);
+
+        assert_eq!(select_digit(0 + 3*2 + 9*1 + 27*2, 0, 3), 0);
+        assert_eq!(select_digit(0 + 3*2 + 9*1 + 27*2, 1, 3), 2);
+        assert_eq!(select_digit(0 + 3*2 + 9*1 + 27*2, 2, 3), 1);
+        assert_eq!(select_digit(0 + 3*2 + 9*1 + 27*2, 3, 3), 2);
+    }
+}
+
+#[derive(Debug, FromLine)]
+#[aoc(pattern = "{}: {...}")]
+pub struct Equation {
+    pub test: i64,
+    pub numbers: Vec<i64>,
+}
+
+pub fn parse_input(challenge_input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse_lines(challenge_input, Equation::from_line)
+}
+
+pub fn challenge1(input: &[Equation]) -> i64 {
+    let mut sum = 0;
+    const OPERATIONS: [fn(i64, i64) -> i64; 2] = [i64::wrapping_add, i64::wrapping_mul];
+    input.iter().for_each(|eq| {
+        let possiblities = i64::pow(2, (eq.numbers.len() - 1) as u32);
+        for combination in 0..possiblities {
+            let mut possibility_sum = eq.numbers[0];
+
+            for op_position in 0..eq.numbers.len() - 1 {
+                let op_index = (i64::pow(2, op_position as u32) & combination) >> op_position;
+                possibility_sum =
+                    OPERATIONS[op_index as usize](possibility_sum, eq.numbers[op_position + 1]);
+            }
+
+            if possibility_sum == eq.test {
+                sum += eq.test;
+                break
+            }
+        }
+    });
+    sum
+}
+
+fn concat_i64(a: i64, b: i64) -> i64 {
+    // Code "borrowed" from https://stackoverflow.com/questions/69297477/getting-the-length-of-an-int
+    let digits_b = b.checked_ilog10().unwrap_or(0) + 1;
+    i64::pow(10, digits_b)*a + b
+}
+
+fn select_digit(number: i64, index: usize, base: i64) -> i64 {
+    (number / i64::pow(base, index as u32)) % base
+}
+
+pub fn challenge2(input: &[Equation]) -> i64 {
+    let mut sum = 0;
+    const OPERATIONS: [fn(i64, i64) -> i64; 3] = [i64::wrapping_add, i64::wrapping_mul, concat_i64];
+    input.iter().for_each(|eq| {
+        let possiblities = i64::pow(3, (eq.numbers.len() - 1) as u32);
+        for combination in 0..possiblities {
+            let mut possibility_sum = eq.numbers[0];
+
+            for op_position in 0..eq.numbers.len() - 1 {
+                let op_index = select_digit(combination, op_position, 3);
+                possibility_sum =
+                    OPERATIONS[op_index as usize](possibility_sum, eq.numbers[op_position + 1]);
+            }
+
+            if possibility_sum == eq.test {
+                sum += eq.test;
+                break
+            }
+        }
+    });
+    sum
+}
+
+#[derive(Clone)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, equations: &Self::Input) -> Answer {
+        challenge1(equations).into()
+    }
+
+    fn part2(&self, equations: &Self::Input) -> Answer {
+        challenge2(equations).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(7, Day7);
+}
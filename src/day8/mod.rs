@@ -0,0 +1,153 @@
+//! Day 8: Resonant Collinearity
+
+use crate::{Answer, AocError, CharacterField, Location, Registry, Solution, SparseField};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn location(&self) -> Location {
+        Location {
+            column: self.x,
+            row: self.y,
+        }
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Position {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+pub fn challenge1(challenge_input: &str) -> i32 {
+    let mut antennas: HashMap<char, Vec<Position>> = HashMap::new();
+
+    let antenna_map: Vec<_> = challenge_input.lines().collect();
+
+    // Get all antennas from the map
+    antenna_map.iter().enumerate().for_each(|(line_no, line)| {
+        line.chars().enumerate().for_each(|(char_no, antenna)| {
+            if antenna != '.' {
+                antennas.entry(antenna).or_default().push(Position {
+                    x: char_no as i32,
+                    y: line_no as i32,
+                });
+            }
+        });
+    });
+
+    // Calculate the positions of the antinodes per antenna type
+    let mut antinodes: SparseField<char> = SparseField::new();
+    antennas.iter().for_each(|(_antenna, positions)| {
+        positions.iter().combinations(2).for_each(|pair| {
+            let position1 = pair.first().unwrap();
+            let position2 = pair.last().unwrap();
+
+            let distance_vector = **position2 - **position1;
+            let antinode1 = **position1 - distance_vector;
+            if antenna_map.has_position(antinode1.x, antinode1.y) {
+                antinodes.put(&antinode1.location(), '#');
+            }
+            let antinode2 = **position2 + distance_vector;
+            if antenna_map.has_position(antinode2.x, antinode2.y) {
+                antinodes.put(&antinode2.location(), '#');
+            }
+        });
+    });
+
+    antinodes.len() as i32
+}
+
+pub fn challenge2(challenge_input: &str) -> i32 {
+    let mut antennas: HashMap<char, Vec<Position>> = HashMap::new();
+
+    let antenna_map: Vec<_> = challenge_input.lines().collect();
+
+    // Get all antennas from the map
+    antenna_map.iter().enumerate().for_each(|(line_no, line)| {
+        line.chars().enumerate().for_each(|(char_no, antenna)| {
+            if antenna != '.' {
+                antennas.entry(antenna).or_default().push(Position {
+                    x: char_no as i32,
+                    y: line_no as i32,
+                });
+            }
+        });
+    });
+
+    // Calculate the positions of the antinodes per antenna type
+    let mut antinodes: SparseField<char> = SparseField::new();
+    antennas.iter().for_each(|(_antenna, positions)| {
+        positions.iter().combinations(2).for_each(|pair| {
+            // The &&Position type here is not entirely clear to me, maybe this can be simplified?
+            let position1 = pair.first().unwrap();
+            let position2 = pair.last().unwrap();
+
+            // Addition 1 for getting part 2 to work
+            antinodes.put(&position1.location(), '#');
+            antinodes.put(&position2.location(), '#');
+
+            let distance_vector = **position2 - **position1;
+
+            let mut antinode1 = **position1 - distance_vector;
+            // The addition 2 to get part 2 to work was just turning the `if` into a while and
+            // adding/subtracting the `distance_vector` over and over again
+            while antenna_map.has_position(antinode1.x, antinode1.y) {
+                antinodes.put(&antinode1.location(), '#');
+                antinode1 = antinode1 - distance_vector;
+            }
+            let mut antinode2 = **position2 + distance_vector;
+            while antenna_map.has_position(antinode2.x, antinode2.y) {
+                antinodes.put(&antinode2.location(), '#');
+                antinode2 = antinode2 + distance_vector;
+            }
+        });
+    });
+
+    antinodes.len() as i32
+}
+
+#[derive(Clone)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, antenna_map: &Self::Input) -> Answer {
+        challenge1(antenna_map).into()
+    }
+
+    fn part2(&self, antenna_map: &Self::Input) -> Answer {
+        challenge2(antenna_map).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(8, Day8);
+}
@@ -0,0 +1,78 @@
+//! Day 9: Disk Fragmenter
+
+use crate::{Answer, AocError, Registry, Solution};
+
+trait ToI32 {
+    fn as_num(&self) -> usize;
+}
+
+impl ToI32 for char {
+    fn as_num(&self) -> usize {
+        (*self as usize) - ('0' as usize)
+    }
+}
+
+// 1) The first impulse was to use .zip() to get a used block and a free block in combination, however
+// this will potentially cause the last entry to be lost because of odd number of entries.
+// 2) Turns out, the answer actually requires a i64 ;-)
+pub fn challenge1(challenge_input: &str) -> i64 {
+    let mut disk_map: Vec<_> = challenge_input
+        .trim()
+        .chars()
+        .enumerate()
+        .flat_map(|(block_index, block_size)| {
+            if block_index % 2 == 0 {
+                vec![(block_index / 2) as i32; block_size.as_num()]
+            } else {
+                vec![-1; block_size.as_num()]
+            }
+        })
+        .collect();
+
+    let mut to_index: usize = 0;
+    let mut from_index: usize = disk_map.len() - 1;
+    while from_index > to_index {
+        if disk_map[to_index] == -1 {
+            // Starting from the back, we could also encounter empty blocks while still having
+            // empty blocks in the front. So we need to skip those too.
+            while disk_map[from_index] == -1 {
+                from_index -= 1;
+            }
+            disk_map.swap(from_index, to_index);
+            from_index -= 1;
+        }
+        to_index += 1;
+    }
+    disk_map
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| index as i64 * if *entry == -1 { 0_i64 } else { *entry as i64 })
+        .sum()
+}
+
+pub fn challenge2(_challenge_input: &str) -> i32 {
+    42
+}
+
+#[derive(Clone)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, disk_map: &Self::Input) -> Answer {
+        challenge1(disk_map).into()
+    }
+
+    fn part2(&self, disk_map: &Self::Input) -> Answer {
+        challenge2(disk_map).into()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.add(9, Day9);
+}
@@ -0,0 +1,133 @@
+// The examples from the puzzle descriptions. They are stored next to the puzzle
+// input as `input_data/dayN/exampleK.txt`, together with an `examples.toml`
+// holding the answers given in the puzzle text:
+//
+//     [example1]
+//     part1 = 12
+//     params = { width = 11, height = 7 }
+
+use crate::answers::{KnownAnswers, PartVerdict};
+use crate::params::{parse_parameters, Parameters};
+use crate::runner::{measure, RunSettings};
+use crate::{registry, AocError, AocErrorType, Part};
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Verdict;
+    use crate::Answer;
+
+    #[test]
+    fn test_loading_examples_with_parameters() {
+        let examples = load_examples(14).unwrap();
+
+        assert_eq!(examples[0].name, "example1");
+        assert_eq!(
+            examples[0].answers.get(Part::One),
+            Some(&Answer::Number(12))
+        );
+        assert_eq!(examples[0].parameters["width"], 11);
+        assert_eq!(examples[0].parameters["height"], 7);
+        assert!(examples[0].input.starts_with("p=0,4 v=3,-3\n"));
+    }
+
+    #[test]
+    fn test_days_without_examples() {
+        assert!(load_examples(26).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_checking_an_example_only_runs_the_known_parts() {
+        let example = &load_examples(14).unwrap()[0];
+
+        let verdicts = check_example(14, example).unwrap();
+
+        assert_eq!(verdicts.len(), 1);
+        assert_eq!(verdicts[0].verdict, Verdict::Pass);
+    }
+}
+
+/// The location of the expected answers of all examples of the given day
+pub fn examples_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("input_data/day{}/examples.toml", day))
+}
+
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: KnownAnswers,
+    pub parameters: Parameters,
+}
+
+impl Example {
+    fn from_table(name: &str, mut table: Table, directory: &Path) -> Result<Example, AocError> {
+        let invalid = |reason: String| AocError::new(AocErrorType::InvalidExamplesFile(reason));
+
+        let parameters = match table.remove("params") {
+            Some(Value::Table(params)) => parse_parameters(params)?,
+            Some(_) => return Err(invalid(format!("'{}.params' must be a table", name))),
+            None => Parameters::new(),
+        };
+
+        let input_path = directory.join(format!("{}.txt", name));
+        let input = read_to_string(&input_path)
+            .map_err(|e| invalid(format!("{}: {}", input_path.display(), e)))?;
+
+        Ok(Example {
+            name: name.to_string(),
+            input,
+            answers: KnownAnswers::from_table(table)?,
+            parameters,
+        })
+    }
+}
+
+/// Load all examples of the given day. A day without an `examples.toml` simply
+/// has no examples.
+pub fn load_examples(day: u32) -> Result<Vec<Example>, AocError> {
+    let invalid = |reason: String| AocError::new(AocErrorType::InvalidExamplesFile(reason));
+
+    let path = examples_path(day);
+    let source = match read_to_string(&path) {
+        Ok(source) => source,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(invalid(error.to_string())),
+    };
+    let table: Table = source
+        .parse()
+        .map_err(|e: toml::de::Error| invalid(e.to_string()))?;
+    let directory = path.parent().unwrap_or(Path::new("."));
+
+    table
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::Table(example) => Example::from_table(&name, example, directory),
+            _ => Err(invalid(format!("'{}' must be a table", name))),
+        })
+        .collect()
+}
+
+/// Run the parts of an example that have a known answer and compare them
+pub fn check_example(day: u32, example: &Example) -> Result<Vec<PartVerdict>, AocError> {
+    let registry = registry();
+    let puzzle = registry
+        .get(day)
+        .ok_or(AocError::new(AocErrorType::UnknownDay(day)))?;
+
+    let settings = RunSettings {
+        parts: Part::BOTH
+            .into_iter()
+            .filter(|part| example.answers.get(*part).is_some())
+            .collect(),
+        parameters: example.parameters.clone(),
+        ..RunSettings::default()
+    };
+    let report = measure(day, puzzle, &example.input, &settings)?;
+
+    Ok(example.answers.verify(&report))
+}
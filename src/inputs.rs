@@ -0,0 +1,180 @@
+// Running one day against the inputs of several people, to find assumptions in
+// a solution that only hold for one of them. Every `name.txt` in a directory
+// is an input, an optional `name.toml` next to it holds the expected answers
+// in the same format as `answers.toml` and an optional `name.params.toml` the
+// parameters for this input.
+
+use crate::answers::{KnownAnswers, PartVerdict, Verdict};
+use crate::params::{load_parameters, params_path, Parameters};
+use crate::runner::{measure, RunSettings};
+use crate::{AocError, AocErrorType, Puzzle};
+use std::fs;
+use std::fs::read_to_string;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::env;
+    use std::process;
+
+    fn directory_with(files: &[(&str, &str)]) -> PathBuf {
+        let directory = env::temp_dir().join(format!("aoc2024-inputs-{}", process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        for (name, content) in files {
+            fs::write(directory.join(name), content).unwrap();
+        }
+        directory
+    }
+
+    #[test]
+    fn test_find_and_run_inputs() {
+        let directory = directory_with(&[
+            ("bob.txt", "1   1\n"),
+            ("bob.toml", "part1 = 0\npart2 = 1\n"),
+            ("alice.txt", "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"),
+            ("alice.toml", "part1 = 11\npart2 = 30\n"),
+            ("carol.txt", "5   5\n"),
+            ("dave.txt", "five five\n"),
+            ("erin.txt", "1   1\n"),
+            ("erin.params.toml", "size = 3\n"),
+            ("notes.md", "not an input"),
+        ]);
+
+        let inputs = find_inputs(&directory).unwrap();
+        assert_eq!(
+            inputs.iter().map(|i| i.name.as_str()).collect::<Vec<_>>(),
+            ["alice", "bob", "carol", "dave", "erin"]
+        );
+
+        let registry = registry();
+        let runs = run_inputs(
+            1,
+            registry.get(1).unwrap(),
+            &inputs,
+            &RunSettings::default(),
+        )
+        .unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(runs[0].disagrees());
+        assert!(!runs[1].disagrees());
+        assert!(!runs[2].disagrees());
+        let InputResult::Answers(verdicts) = &runs[2].result else {
+            panic!("carol's input should have been solved");
+        };
+        assert_eq!(verdicts[0].verdict, Verdict::Missing);
+        let InputResult::Failed(error) = &runs[3].result else {
+            panic!("dave's input should have been rejected");
+        };
+        assert!(matches!(error.error_type(), AocErrorType::Parse(_)));
+        assert!(runs[3].disagrees());
+        assert!(matches!(
+            runs[4].result,
+            InputResult::Failed(ref error)
+                if matches!(error.error_type(), AocErrorType::UnknownParameter(_))
+        ));
+    }
+}
+
+/// One input file with the answers its owner knows to be correct
+#[derive(Debug, Clone)]
+pub struct InputFile {
+    pub name: String,
+    pub path: PathBuf,
+    pub answers: KnownAnswers,
+    pub parameters: Parameters,
+}
+
+/// All inputs of a directory, sorted by name
+pub fn find_inputs(directory: &Path) -> Result<Vec<InputFile>, AocError> {
+    let not_found = |e: std::io::Error| {
+        AocError::new(AocErrorType::InvalidArgument(format!(
+            "{}: {}",
+            directory.display(),
+            e
+        )))
+    };
+
+    let mut inputs = vec![];
+    for entry in fs::read_dir(directory).map_err(not_found)? {
+        let path = entry.map_err(not_found)?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let answers = KnownAnswers::load_from(&path.with_extension("toml"))?;
+            let parameters = load_parameters(&params_path(&path))?;
+            inputs.push(InputFile {
+                name,
+                path,
+                answers,
+                parameters,
+            });
+        }
+    }
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+#[derive(Debug, Clone)]
+pub enum InputResult {
+    Answers(Vec<PartVerdict>),
+    /// The solution panicked, which usually means it assumes something that
+    /// doesn't hold for this input
+    Panicked,
+    /// The input couldn't be parsed or the parameters don't fit the day
+    Failed(AocError),
+}
+
+#[derive(Debug, Clone)]
+pub struct InputRun {
+    pub name: String,
+    pub result: InputResult,
+}
+
+impl InputRun {
+    /// Whether the solution gave a wrong answer or no answer at all
+    pub fn disagrees(&self) -> bool {
+        match &self.result {
+            InputResult::Answers(verdicts) => verdicts
+                .iter()
+                .any(|v| v.answer.is_err() || matches!(v.verdict, Verdict::Fail { .. })),
+            InputResult::Panicked | InputResult::Failed(_) => true,
+        }
+    }
+}
+
+/// Solve the parts for every input and compare with the expected answers. The
+/// parameters of the settings win over the parameters of the inputs.
+pub fn run_inputs(
+    day: u32,
+    puzzle: &dyn Puzzle,
+    inputs: &[InputFile],
+    settings: &RunSettings,
+) -> Result<Vec<InputRun>, AocError> {
+    let mut runs = vec![];
+    for input in inputs {
+        let content = read_to_string(&input.path)
+            .map_err(|_| AocError::new(AocErrorType::InputDataNotFound))?;
+        let mut parameters = input.parameters.clone();
+        parameters.extend(settings.parameters.clone());
+        let settings = RunSettings {
+            parameters,
+            ..settings.clone()
+        };
+        let result = match catch_unwind(AssertUnwindSafe(|| {
+            measure(day, puzzle, &content, &settings)
+        })) {
+            Ok(Ok(report)) => InputResult::Answers(input.answers.verify(&report)),
+            Ok(Err(error)) => InputResult::Failed(error),
+            Err(_) => InputResult::Panicked,
+        };
+        runs.push(InputRun {
+            name: input.name.clone(),
+            result,
+        });
+    }
+    Ok(runs)
+}
@@ -1,12 +1,120 @@
-use itertools::Itertools;
+// The days keep their unit tests at the top of the file, in front of the code
+#![allow(clippy::items_after_test_module)]
+
+// The code generated by the derive macros refers to this crate as `aoc2024`
+extern crate self as aoc2024;
+
+use parse::ParseError;
 use std::error::Error;
-use std::ops::{Add, Deref};
+use std::ops::{Add, Index, IndexMut};
+use std::str::FromStr;
 use std::{env, fmt};
+use tile::{Cell, Markers};
+
+pub mod answers;
+pub mod cancel;
+pub mod client;
+pub mod config;
+pub mod examples;
+pub mod inputs;
+pub mod params;
+pub mod parse;
+pub mod reader;
+pub mod runner;
+pub mod solution;
+pub mod space;
+pub mod sparse;
+pub mod submissions;
+pub mod table;
+pub mod tile;
+
+pub use reader::InputReader;
+pub use runner::{input_path, measure_day, run_day, RunSettings};
+pub use solution::{Answer, Part, Puzzle, Registry, Solution};
+pub use space::{Field3, Location3};
+pub use sparse::SparseField;
+pub use table::TableReader;
+
+// Declares the modules of all days and creates the `registry()` function
+// that registers every one of them
+macro_rules! days {
+    ($($day:ident,)*) => {
+        $(pub mod $day;)*
+
+        /// Create a registry containing all days
+        pub fn registry() -> Registry {
+            let mut registry = Registry::new();
+            $($day::register(&mut registry);)*
+            registry
+        }
+    };
+}
+
+days! {
+    day1,
+    day2,
+    day3,
+    day4,
+    day5,
+    day6,
+    day7,
+    day8,
+    day9,
+    day10,
+    day11,
+    day12,
+    day13,
+    day14,
+    day15,
+    day16,
+    day17,
+    day18,
+    day19,
+}
 
 #[derive(Debug, Clone)]
 pub enum AocErrorType {
     InputDataNotFound,
     MissingArgument,
+    InvalidArgument(String),
+    UnknownDay(u32),
+    InvalidAnswersFile(String),
+    WrongAnswers(usize),
+    UnknownParameter(String),
+    InvalidExamplesFile(String),
+    InvalidConfig(String),
+    MissingSession,
+    RequestFailed(String),
+    WriteFailed(String),
+    InvalidSubmissionLog(String),
+    InvalidParameter(String),
+    Parse(ParseError),
+}
+
+impl fmt::Display for AocErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocErrorType::InputDataNotFound => write!(f, "input data not found"),
+            AocErrorType::MissingArgument => write!(f, "missing argument"),
+            AocErrorType::InvalidArgument(arg) => write!(f, "invalid argument '{}'", arg),
+            AocErrorType::UnknownDay(day) => write!(f, "there is no solution for day {}", day),
+            AocErrorType::InvalidAnswersFile(reason) => write!(f, "invalid answers: {}", reason),
+            AocErrorType::WrongAnswers(count) => write!(f, "wrong answers: {}", count),
+            AocErrorType::UnknownParameter(name) => write!(f, "unknown parameter '{}'", name),
+            AocErrorType::InvalidExamplesFile(reason) => {
+                write!(f, "invalid examples: {}", reason)
+            }
+            AocErrorType::InvalidConfig(reason) => write!(f, "invalid config: {}", reason),
+            AocErrorType::MissingSession => write!(f, "no session cookie configured"),
+            AocErrorType::RequestFailed(reason) => write!(f, "request failed: {}", reason),
+            AocErrorType::WriteFailed(reason) => write!(f, "write failed: {}", reason),
+            AocErrorType::InvalidSubmissionLog(reason) => {
+                write!(f, "invalid submission log: {}", reason)
+            }
+            AocErrorType::InvalidParameter(reason) => write!(f, "invalid parameter: {}", reason),
+            AocErrorType::Parse(error) => write!(f, "invalid input, {}", error.render()),
+        }
+    }
 }
 #[derive(Debug, Clone)]
 pub struct AocError {
@@ -17,11 +125,21 @@ impl AocError {
     pub fn new(err: AocErrorType) -> AocError {
         AocError { err_type: err }
     }
+
+    pub fn error_type(&self) -> &AocErrorType {
+        &self.err_type
+    }
 }
 
 impl fmt::Display for AocError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "AocError: {:?}", self.err_type)
+        write!(f, "{}", self.err_type)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::new(AocErrorType::Parse(error))
     }
 }
 
@@ -33,46 +151,125 @@ pub fn get_arg1() -> Option<String> {
 }
 
 pub trait SimpleChar {
+    /// The value of a digit, panics on any other character. Use `try_i32`
+    /// when the character isn't known to be a digit.
     fn as_i32(&self) -> i32;
+    fn try_i32(&self) -> Result<i32, ParseError>;
 }
 
 impl SimpleChar for char {
     fn as_i32(&self) -> i32 {
-        (*self as i32) - ('0' as i32)
+        self.try_i32().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_i32(&self) -> Result<i32, ParseError> {
+        self.to_digit(10).map(|digit| digit as i32).ok_or_else(|| {
+            ParseError::new(&self.to_string(), 0, &self.to_string(), "expected a digit")
+        })
     }
 }
 
+/// Parsing of numbers for trusted input. The `get_` and `to_` functions panic
+/// on malformed input, the `try_` functions return an error that tells where
+/// the problem is.
 pub trait SimpleParse {
     fn get_i32(&self) -> i32;
     fn get_i64(&self) -> i64;
     fn to_pair(&self) -> (i32, i32);
     fn to_pair_i64(&self) -> (i64, i64);
+
+    fn try_i32(&self) -> Result<i32, ParseError>;
+    fn try_i64(&self) -> Result<i64, ParseError>;
+    /// The two whitespace separated numbers of a line, see `TableReader::row`
+    fn try_pair(&self) -> Result<(i32, i32), ParseError>;
+    fn try_pair_i64(&self) -> Result<(i64, i64), ParseError>;
+
+    /// All numbers in the text, whatever is between them. A `-` in front of
+    /// a number is its sign, unless it follows another number like in `1-3`.
+    /// A number too large for the type is an error.
+    fn ints(&self) -> Result<Vec<i32>, ParseError>;
+    fn ints_i64(&self) -> Result<Vec<i64>, ParseError>;
+}
+
+// The numbers of a string for `ints`, with their sign and byte offset
+fn numbers_in(s: &str) -> Vec<(usize, &str)> {
+    let bytes = s.as_bytes();
+    let mut numbers = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let mut start = index;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        numbers.push((start, &s[start..index]));
+    }
+    numbers
 }
 
 impl SimpleParse for str {
     fn get_i32(&self) -> i32 {
-        self.parse::<i32>().unwrap()
+        self.try_i32().unwrap_or_else(|error| panic!("{}", error))
     }
 
     fn get_i64(&self) -> i64 {
-        self.parse::<i64>().unwrap()
+        self.try_i64().unwrap_or_else(|error| panic!("{}", error))
     }
 
     fn to_pair(&self) -> (i32, i32) {
-        self.split_whitespace()
-            .take(2)
-            .map(|x| x.get_i32())
-            .collect_tuple()
-            .unwrap()
+        self.try_pair().unwrap_or_else(|error| panic!("{}", error))
     }
 
     fn to_pair_i64(&self) -> (i64, i64) {
-        self.split_whitespace()
-            .take(2)
-            .map(|x| x.get_i64())
-            .collect_tuple()
-            .unwrap()
+        self.try_pair_i64()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_i32(&self) -> Result<i32, ParseError> {
+        self.parse::<i32>()
+            .map_err(|_| ParseError::new(self, 0, self, "expected a number"))
+    }
+
+    fn try_i64(&self) -> Result<i64, ParseError> {
+        self.parse::<i64>()
+            .map_err(|_| ParseError::new(self, 0, self, "expected a number"))
+    }
+
+    fn try_pair(&self) -> Result<(i32, i32), ParseError> {
+        TableReader::new().row(self)
+    }
+
+    fn try_pair_i64(&self) -> Result<(i64, i64), ParseError> {
+        TableReader::new().row(self)
     }
+
+    fn ints(&self) -> Result<Vec<i32>, ParseError> {
+        parse_numbers(self)
+    }
+
+    fn ints_i64(&self) -> Result<Vec<i64>, ParseError> {
+        parse_numbers(self)
+    }
+}
+
+fn parse_numbers<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    numbers_in(s)
+        .into_iter()
+        .map(|(offset, number)| {
+            number
+                .parse()
+                .map_err(|_| ParseError::new(s, offset, number, "expected a smaller number"))
+        })
+        .collect()
 }
 
 // ------------------------------------------------------------------
@@ -96,12 +293,85 @@ pub struct Location {
 // east, west etc. for the Direction so that we can use
 // move-relative direction like forward, backward, left, right
 // for another type
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     Left,
     Up,
     Right,
     Down,
+    UpLeft,
+    UpRight,
+    DownRight,
+    DownLeft,
+}
+
+impl Direction {
+    const ALL: [Direction; 8] = [
+        Direction::Left,
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+    ];
+
+    /// How far a step in this direction moves, as `(columns, rows)`
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownRight => (1, 1),
+            Direction::DownLeft => (-1, 1),
+        }
+    }
+
+    /// The direction of a single step, `None` for any other offset
+    pub fn from_offset(offset: (i32, i32)) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == offset)
+    }
+}
+
+/// Which of the surrounding locations count as neighbors
+#[derive(Debug, Clone, Copy)]
+pub enum Neighborhood<'a> {
+    /// Left, right, up and down
+    VonNeumann,
+    /// All eight surrounding locations
+    Moore,
+    /// Only the four corners
+    Diagonal,
+    /// Any offsets as `(columns, rows)`, which can be further away than a
+    /// single step, like the moves of a knight
+    Custom(&'a [(i32, i32)]),
+}
+
+impl<'a> Neighborhood<'a> {
+    /// The offsets of the neighbors as `(columns, rows)`
+    pub fn offsets(&self) -> &'a [(i32, i32)] {
+        match self {
+            Neighborhood::VonNeumann => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+            Neighborhood::Moore => &[
+                (-1, 0),
+                (1, 0),
+                (0, -1),
+                (0, 1),
+                (-1, -1),
+                (1, -1),
+                (1, 1),
+                (-1, 1),
+            ],
+            Neighborhood::Diagonal => &[(-1, -1), (1, -1), (1, 1), (-1, 1)],
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -128,106 +398,420 @@ impl Add<DirectionRelative> for Direction {
     type Output = Direction;
 
     fn add(self, rhs: DirectionRelative) -> Self::Output {
-        match rhs {
-            DirectionRelative::Forward => self,
-            DirectionRelative::Left => match self {
-                Direction::Up => Direction::Left,
-                Direction::Right => Direction::Up,
-                Direction::Down => Direction::Right,
-                Direction::Left => Direction::Down,
-            },
-            DirectionRelative::Right => match self {
-                Direction::Up => Direction::Right,
-                Direction::Right => Direction::Down,
-                Direction::Down => Direction::Left,
-                Direction::Left => Direction::Up,
-            },
-            DirectionRelative::Reverse => match self {
-                Direction::Up => Direction::Down,
-                Direction::Right => Direction::Left,
-                Direction::Down => Direction::Up,
-                Direction::Left => Direction::Right,
-            },
-        }
+        // The directions in clockwise order, every turn is a quarter
+        const CLOCKWISE: [Direction; 8] = [
+            Direction::Up,
+            Direction::UpRight,
+            Direction::Right,
+            Direction::DownRight,
+            Direction::Down,
+            Direction::DownLeft,
+            Direction::Left,
+            Direction::UpLeft,
+        ];
+        let eighths = match rhs {
+            DirectionRelative::Forward => 0,
+            DirectionRelative::Right => 2,
+            DirectionRelative::Reverse => 4,
+            DirectionRelative::Left => 6,
+        };
+        let index = CLOCKWISE.iter().position(|&d| d == self).unwrap();
+        CLOCKWISE[(index + eighths) % CLOCKWISE.len()]
     }
 }
 
 impl Location {
     pub fn in_direction(&self, direction: Direction) -> Location {
-        match direction {
-            Direction::Left => Location {
-                column: self.column - 1,
-                row: self.row,
-            },
-            Direction::Up => Location {
-                column: self.column,
-                row: self.row - 1,
-            },
-            Direction::Right => Location {
-                column: self.column + 1,
-                row: self.row,
-            },
-            Direction::Down => Location {
-                column: self.column,
-                row: self.row + 1,
-            },
+        let (dx, dy) = direction.offset();
+        Location {
+            column: self.column + dx,
+            row: self.row + dy,
         }
     }
 
-    pub fn neighbors(&self) -> impl Iterator<Item = Location> + '_
-where {
-        let neighbor_positions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-        neighbor_positions.into_iter().map(move |(dx, dy)| {
-            let nx = self.column + dx;
-            let ny = self.row + dy;
-            Location {
-                column: nx,
-                row: ny,
-            }
+    /// The surrounding locations of the neighborhood, with the direction
+    /// that leads to them. Neighbors further away than a single step have no
+    /// direction.
+    pub fn neighbors<'a>(
+        &self,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Location, Option<Direction>)> + 'a {
+        let location = *self;
+        neighborhood.offsets().iter().map(move |&(dx, dy)| {
+            let neighbor = Location {
+                column: location.column + dx,
+                row: location.row + dy,
+            };
+            (neighbor, Direction::from_offset((dx, dy)))
         })
     }
 }
 
-#[derive(Debug)]
-pub struct Field<T>(Vec<Vec<T>>);
+/// The entries along a row, column or diagonal of a field, with their location
+pub type FieldLine<'a, T> = Vec<(Location, &'a T)>;
+
+/// A rectangular grid of entries. The entries are kept row by row in a
+/// single `Vec`, so a location is turned into an index in constant time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field<T> {
+    cells: Vec<T>,
+    width: usize,
+    topology: Topology,
+}
+
+/// What lies beyond the edges of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    /// Nothing, locations outside the field have no entry
+    #[default]
+    Bounded,
+    /// The field repeats, leaving it on one side enters it on the opposite
+    /// side, like the room of the robots of day 14
+    Toroidal,
+}
 
 impl<T> Field<T> {
+    /// A field with the given rows, which must all have the same length or
+    /// the code will panic.
     pub fn new(v: Vec<Vec<T>>) -> Field<T> {
-        Field(v)
+        let width = v.first().map_or(0, Vec::len);
+        assert!(
+            v.iter().all(|row| row.len() == width),
+            "all rows of a field must have the same length"
+        );
+        Field {
+            cells: v.into_iter().flatten().collect(),
+            width,
+            topology: Topology::Bounded,
+        }
     }
 
-    pub fn at(&self, location: &Location) -> Option<T>
+    /// Read a grid of characters, every line of the input is a row. The
+    /// `mapper` gives the tile of a character, or the marker standing on a
+    /// tile, and `None` for characters that don't belong in the grid. Returns
+    /// the field and the locations of all markers.
+    ///
+    /// Every row needs as many tiles as the first one, empty lines at the end
+    /// of the input are ignored.
+    pub fn parse(
+        input: &str,
+        mapper: impl Fn(char) -> Option<Cell<T>>,
+    ) -> Result<(Field<T>, Markers), ParseError> {
+        tile::parse_grid(input, mapper, "expected a tile of the grid")
+    }
+
+    /// A field of the given size with the value `f` returns for every location
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Location) -> T) -> Field<T> {
+        Field {
+            cells: (0..width * height)
+                .map(|index| f(Self::location_in(width, index)))
+                .collect(),
+            width,
+            topology: Topology::Bounded,
+        }
+    }
+
+    /// A field of the given size with the same value everywhere
+    pub fn filled(width: usize, height: usize, value: T) -> Field<T>
     where
-        T: Copy,
+        T: Clone,
     {
-        if (location.row < 0) || (location.row as usize >= self.0.len()) {
-            return None;
+        Field {
+            cells: vec![value; width * height],
+            width,
+            topology: Topology::Bounded,
         }
-        if (location.column < 0)
-            || (location.column as usize >= self.0[location.row as usize].len())
-        {
-            return None;
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        match self.width {
+            0 => 0,
+            width => self.cells.len() / width,
+        }
+    }
+
+    /// The same field with another topology
+    pub fn with_topology(mut self, topology: Topology) -> Field<T> {
+        self.topology = topology;
+        self
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// The location moved onto the field by going around it as often as
+    /// needed, whatever the topology of the field. The field must not be
+    /// empty.
+    pub fn wrap(&self, location: &Location) -> Location {
+        Location {
+            column: location.column.rem_euclid(self.width() as i32),
+            row: location.row.rem_euclid(self.height() as i32),
+        }
+    }
+
+    // The location that has the entry for `location`, which is only another
+    // one for a toroidal field
+    fn normalize(&self, location: &Location) -> Location {
+        match self.topology {
+            Topology::Toroidal if !self.cells.is_empty() => self.wrap(location),
+            _ => *location,
         }
-        Some(self.0[location.row as usize][location.column as usize])
     }
 
-    pub fn at_mut(&mut self, location: &Location) -> Option<&mut T>
+    /// The location one step away in the given direction, if there is an
+    /// entry for it. On a toroidal field this wraps around the edges.
+    pub fn in_direction(&self, location: &Location, direction: Direction) -> Option<Location> {
+        let next = self.normalize(&location.in_direction(direction));
+        self.contains(&next).then_some(next)
+    }
+
+    /// Whether the location is within the edges of the field
+    pub fn contains(&self, location: &Location) -> bool {
+        location.row >= 0
+            && location.column >= 0
+            && (location.column as usize) < self.width
+            && (location.row as usize) < self.height()
+    }
+
+    /// The position of the location in the entries, row by row. On a
+    /// toroidal field every location has one.
+    pub fn index_of(&self, location: &Location) -> Option<usize> {
+        let location = self.normalize(location);
+        self.contains(&location)
+            .then(|| location.row as usize * self.width + location.column as usize)
+    }
+
+    /// The location of the entry with the given index, see `index_of`
+    pub fn location_of(&self, index: usize) -> Location {
+        Self::location_in(self.width, index)
+    }
+
+    fn location_in(width: usize, index: usize) -> Location {
+        Location {
+            column: (index % width) as i32,
+            row: (index / width) as i32,
+        }
+    }
+
+    pub fn get(&self, location: &Location) -> Option<&T> {
+        self.index_of(location).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, location: &Location) -> Option<&mut T> {
+        self.index_of(location).map(|index| &mut self.cells[index])
+    }
+
+    pub fn at(&self, location: &Location) -> Option<T>
     where
         T: Copy,
     {
-        if location.row >= 0 && (location.row as usize) < self.0.len() {
-            if let Some(row) = self.0.get_mut(location.row as usize) {
-                return row.get_mut(location.column as usize);
-            }
-        }
-        None
+        self.get(location).copied()
+    }
+
+    pub fn at_mut(&mut self, location: &Location) -> Option<&mut T> {
+        self.get_mut(location)
     }
 
     /// Replace the entry at the given location with the new `value`.
     /// The location must exist or the code will panic.
     pub fn put(&mut self, location: &Location, value: T) {
-        self.0[location.row as usize][location.column as usize] = value;
+        self[*location] = value;
+    }
+
+    /// The entries of one row, starting at the top with row 0
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height()).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        (row < self.height()).then(|| &mut self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    /// All rows from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks` can't take a size of 0, an empty field has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// All locations of the field, row by row. This doesn't borrow the field,
+    /// so it can be changed while going through the locations.
+    pub fn locations(&self) -> impl Iterator<Item = Location> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Self::location_in(width, index))
+    }
+
+    /// All entries of the field by reference, together with their location
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> + '_ {
+        self.locations().zip(&self.cells)
+    }
+
+    /// All entries of the field for changing them, together with their location
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Location, &mut T)> + '_ {
+        self.locations().zip(&mut self.cells)
+    }
+
+    /// A field of the same size with `f` applied to every entry
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Field<U> {
+        Field {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            topology: self.topology,
+        }
+    }
+
+    /// The entries of both fields side by side. Both fields must have the
+    /// same size or the code will panic.
+    pub fn zip<U>(&self, other: &Field<U>) -> Field<(T, U)>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        assert_eq!(
+            (self.width(), self.height()),
+            (other.width(), other.height()),
+            "only fields of the same size can be zipped"
+        );
+        Field {
+            cells: self
+                .cells
+                .iter()
+                .cloned()
+                .zip(other.cells.iter().cloned())
+                .collect(),
+            width: self.width,
+            topology: self.topology,
+        }
+    }
+
+    /// Rows become columns, the entry at column 2 of row 1 moves to column 1
+    /// of row 2
+    pub fn transpose(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        Field::from_fn(self.height(), self.width(), |location| {
+            self[Location {
+                column: location.row,
+                row: location.column,
+            }]
+            .clone()
+        })
+    }
+
+    /// Turned a quarter clockwise, the left column becomes the top row
+    pub fn rotate_cw(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        let height = self.height() as i32;
+        Field::from_fn(self.height(), self.width(), |location| {
+            self[Location {
+                column: location.row,
+                row: height - 1 - location.column,
+            }]
+            .clone()
+        })
+    }
+
+    /// Turned a quarter counterclockwise, the top row becomes the left column
+    pub fn rotate_ccw(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        let width = self.width() as i32;
+        Field::from_fn(self.height(), self.width(), |location| {
+            self[Location {
+                column: width - 1 - location.row,
+                row: location.column,
+            }]
+            .clone()
+        })
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        let width = self.width() as i32;
+        Field::from_fn(self.width(), self.height(), |location| {
+            self[Location {
+                column: width - 1 - location.column,
+                row: location.row,
+            }]
+            .clone()
+        })
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        let height = self.height() as i32;
+        Field::from_fn(self.width(), self.height(), |location| {
+            self[Location {
+                column: location.column,
+                row: height - 1 - location.row,
+            }]
+            .clone()
+        })
+    }
+
+    // The entries from `start` on, taking steps of `dx` and `dy` until the
+    // edge of the field
+    fn line_from(&self, start: Location, (dx, dy): (i32, i32)) -> FieldLine<'_, T> {
+        std::iter::successors(Some(start), |location| {
+            Some(Location {
+                column: location.column + dx,
+                row: location.row + dy,
+            })
+        })
+        // Not `get`, which never runs out of entries on a toroidal field
+        .take_while(|location| self.contains(location))
+        .map(|location| (location, &self[location]))
+        .collect()
+    }
+
+    /// Every row from left to right, from the top row on
+    pub fn row_lines(&self) -> impl Iterator<Item = FieldLine<'_, T>> + '_ {
+        (0..self.height() as i32).map(|row| self.line_from(Location { column: 0, row }, (1, 0)))
+    }
+
+    /// Every column from top to bottom, from the left column on
+    pub fn column_lines(&self) -> impl Iterator<Item = FieldLine<'_, T>> + '_ {
+        (0..self.width() as i32).map(|column| self.line_from(Location { column, row: 0 }, (0, 1)))
+    }
+
+    /// The diagonals going down to the right, starting with the one in the
+    /// top right corner and ending with the one in the bottom left corner
+    pub fn main_diagonals(&self) -> impl Iterator<Item = FieldLine<'_, T>> + '_ {
+        let from_top = (0..self.width() as i32)
+            .rev()
+            .map(|column| Location { column, row: 0 });
+        let from_left = (1..self.height() as i32).map(|row| Location { column: 0, row });
+        from_top
+            .chain(from_left)
+            .map(|start| self.line_from(start, (1, 1)))
+    }
+
+    /// The diagonals going up to the right, starting with the one in the top
+    /// left corner and ending with the one in the bottom right corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = FieldLine<'_, T>> + '_ {
+        let bottom = self.height() as i32 - 1;
+        let from_left = (0..self.height() as i32).map(|row| Location { column: 0, row });
+        let from_bottom = (1..self.width() as i32).map(move |column| Location {
+            column,
+            row: bottom,
+        });
+        from_left
+            .chain(from_bottom)
+            .map(|start| self.line_from(start, (1, -1)))
     }
 
     /// Returns an iterator that returns all entries of this field as a
@@ -236,83 +820,76 @@ impl<T> Field<T> {
     where
         T: Copy,
     {
-        self.0.iter().enumerate().flat_map(|(row, line)| {
-            line.iter().enumerate().map(move |(column, entry)| {
-                (
-                    Location {
-                        column: column as i32,
-                        row: row as i32,
-                    },
-                    *entry,
-                )
-            })
-        })
+        self.iter().map(|(location, entry)| (location, *entry))
     }
 
     /// Iterate through actually available neighbors.
     ///
-    /// Returns an iterator that will return a tuple for each vertical and horizontal neighbor
+    /// Returns an iterator that will return a tuple for each neighbor of the neighborhood
     /// that is actually on the field, neighbors outside the field are ignored.
-    /// The returned tuple contains the location of the neighbor and the value of that neighbor.
+    /// The returned tuple contains the location of the neighbor, the direction that leads
+    /// there (see `Location::neighbors`) and the value of that neighbor.
     ///
     /// To iterate over all neighbors, including those outside of the Field, use `all_neighbors()`.
     pub fn actual_neighbors<'a>(
         &'a self,
-        location: &'a Location,
-    ) -> impl Iterator<Item = (Location, T)> + 'a
+        location: &Location,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Location, Option<Direction>, T)> + 'a
     where
         T: Copy,
     {
-        let neighbor_positions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-        neighbor_positions.into_iter().filter_map(move |(dx, dy)| {
-            let nx = location.column + dx;
-            let ny = location.row + dy;
-            let neighbor_location = Location {
-                column: nx,
-                row: ny,
-            };
-
-            self.0.get(ny as usize).and_then(|row| {
-                row.get(nx as usize)
-                    .cloned()
-                    .map(|val| (neighbor_location, val))
-            })
-        })
+        self.all_neighbors(location, neighborhood).filter_map(
+            |(neighbor_location, direction, value)| {
+                value.map(|val| (neighbor_location, direction, val))
+            },
+        )
     }
 
     pub fn all_neighbors<'a>(
         &'a self,
-        location: &'a Location,
-    ) -> impl Iterator<Item = (Location, Option<T>)> + 'a
+        location: &Location,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Location, Option<Direction>, Option<T>)> + 'a
     where
         T: Copy,
     {
-        let neighbor_positions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-        neighbor_positions.into_iter().map(move |(dx, dy)| {
-            let nx = location.column + dx;
-            let ny = location.row + dy;
-            let neighbor_location = Location {
-                column: nx,
-                row: ny,
-            };
+        location
+            .neighbors(neighborhood)
+            .map(|(neighbor_location, direction)| {
+                let neighbor_location = self.normalize(&neighbor_location);
+                (neighbor_location, direction, self.at(&neighbor_location))
+            })
+    }
+}
 
-            (
-                neighbor_location,
-                self.0
-                    .get(ny as usize)
-                    .and_then(|row| row.get(nx as usize).cloned()),
-            )
-        })
+/// Every row on a line of its own, like the grid of a puzzle input
+impl<T: fmt::Display> fmt::Display for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for entry in row {
+                write!(f, "{}", entry)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
-impl<T> Deref for Field<T> {
-    type Target = Vec<Vec<T>>;
+impl<T> Index<Location> for Field<T> {
+    type Output = T;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// The entry at the location, which must be on the field
+    fn index(&self, location: Location) -> &T {
+        self.get(&location)
+            .unwrap_or_else(|| panic!("{:?} is not on the field", location))
+    }
+}
+
+impl<T> IndexMut<Location> for Field<T> {
+    fn index_mut(&mut self, location: Location) -> &mut T {
+        self.get_mut(&location)
+            .unwrap_or_else(|| panic!("{:?} is not on the field", location))
     }
 }
 
@@ -323,6 +900,11 @@ pub trait CharacterField {
     fn has_position(&self, x: i32, y: i32) -> bool;
 
     fn with_char_at(&self, x: i32, y: i32, new_ch: char) -> Vec<String>;
+
+    /// The characters as a field, to use its rows, columns and diagonals.
+    /// Empty lines at the end and `\r` of Windows line endings are ignored,
+    /// lines of different lengths are an error, see `Field::parse`.
+    fn to_field(&self) -> Result<Field<char>, ParseError>;
 }
 
 impl CharacterField for Vec<&str> {
@@ -355,4 +937,9 @@ impl CharacterField for Vec<&str> {
             })
             .collect()
     }
+
+    fn to_field(&self) -> Result<Field<char>, ParseError> {
+        let (field, _) = tile::parse_grid(&self.join("\n"), |ch| Some(Cell::Tile(ch)), "")?;
+        Ok(field)
+    }
 }
@@ -1,3 +1,425 @@
-fn main() {
-    println!("Hello, world!");
+use aoc2024::answers::{KnownAnswers, Verdict};
+use aoc2024::client::{fetch_input, AocClient, Outcome};
+use aoc2024::config::Config;
+use aoc2024::inputs::{find_inputs, run_inputs, InputResult};
+use aoc2024::params::parse_assignment;
+use aoc2024::runner::summary_table;
+use aoc2024::submissions::{Submission, SubmissionLog};
+use aoc2024::{
+    input_path, measure_day, registry, Answer, AocError, AocErrorType, Part, Registry, RunSettings,
+};
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = r#"Usage: aoc <command> [options]
+
+Commands:
+  run <day|all>                  Run the solution of one day or of all days
+      --part <1|2>               Only run the given part
+      --repeat <n>               Run everything n times and report min/median/max timings
+      --inputs <dir>             Run one day on every <name>.txt in dir and compare with the
+                                 answers in <name>.toml, reporting the inputs that disagree
+      --param <name>=<value>     Change a parameter of the puzzle, can be given several times
+      --timeout <seconds>        Give up on a part that takes longer and report it as timed out
+  params <day>                   List the parameters of a day with their default values
+  verify [day|all]               Check the answers against input_data/dayN/answers.toml
+      --timeout <seconds>        Give up on a part that takes longer and count it as failed
+  fetch <day|all>                Download missing puzzle inputs into input_data/dayN/input.txt,
+                                 using the session cookie from aoc.toml or AOC_SESSION
+  submit <day> <1|2> [answer]    Submit the answer of a part, computed from the input unless given,
+                                 and record the result in input_data/dayN/submissions.toml
+      --force                    Submit even if earlier submissions already rule out the answer"#;
+
+#[derive(Debug, PartialEq)]
+enum DaySelection {
+    All,
+    Single(u32),
+}
+
+impl DaySelection {
+    fn parse(arg: &str) -> Result<DaySelection, AocError> {
+        if arg == "all" {
+            return Ok(DaySelection::All);
+        }
+        let day = arg
+            .trim_start_matches("day")
+            .parse::<u32>()
+            .map_err(|_| AocError::new(AocErrorType::InvalidArgument(arg.to_string())))?;
+        Ok(DaySelection::Single(day))
+    }
+}
+
+struct RunOptions {
+    days: Option<DaySelection>,
+    settings: RunSettings,
+    inputs: Option<PathBuf>,
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, AocError> {
+    let mut days = None;
+    let mut settings = RunSettings::default();
+    let mut inputs = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args
+                    .next()
+                    .ok_or(AocError::new(AocErrorType::MissingArgument))?;
+                settings.parts = vec![part.parse()?];
+            }
+            "--repeat" => {
+                let count = args
+                    .next()
+                    .ok_or(AocError::new(AocErrorType::MissingArgument))?;
+                settings.repetitions = count
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or(AocError::new(AocErrorType::InvalidArgument(count.clone())))?;
+            }
+            "--inputs" => {
+                let directory = args
+                    .next()
+                    .ok_or(AocError::new(AocErrorType::MissingArgument))?;
+                inputs = Some(PathBuf::from(directory));
+            }
+            "--param" => {
+                let assignment = args
+                    .next()
+                    .ok_or(AocError::new(AocErrorType::MissingArgument))?;
+                let (name, value) = parse_assignment(assignment)?;
+                settings.parameters.insert(name, value);
+            }
+            "--timeout" => {
+                let seconds = args
+                    .next()
+                    .ok_or(AocError::new(AocErrorType::MissingArgument))?;
+                let timeout = seconds
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .filter(|timeout| !timeout.is_zero())
+                    .ok_or(AocError::new(AocErrorType::InvalidArgument(
+                        seconds.clone(),
+                    )))?;
+                settings.timeout = Some(timeout);
+            }
+            _ if days.is_none() => days = Some(DaySelection::parse(arg)?),
+            _ => return Err(AocError::new(AocErrorType::InvalidArgument(arg.clone()))),
+        }
+    }
+
+    Ok(RunOptions {
+        days,
+        settings,
+        inputs,
+    })
+}
+
+/// Run one day against all inputs in a directory and fail when any of them
+/// disagrees with its expected answers
+fn run_directory(
+    registry: &Registry,
+    day: u32,
+    directory: &Path,
+    settings: &RunSettings,
+) -> Result<(), Box<dyn Error>> {
+    let puzzle = registry
+        .get(day)
+        .ok_or(AocError::new(AocErrorType::UnknownDay(day)))?;
+    let runs = run_inputs(day, puzzle, &find_inputs(directory)?, settings)?;
+
+    for run in &runs {
+        match &run.result {
+            InputResult::Answers(verdicts) => {
+                println!("{}", run.name);
+                for verdict in verdicts {
+                    println!("  {}", verdict);
+                }
+            }
+            InputResult::Panicked => println!("{}: panicked", run.name),
+            InputResult::Failed(error) => println!("{}: {}", run.name, error),
+        }
+    }
+
+    let disagreeing: Vec<&str> = runs
+        .iter()
+        .filter(|run| run.disagrees())
+        .map(|run| run.name.as_str())
+        .collect();
+    if !disagreeing.is_empty() {
+        println!();
+        println!(
+            "{} of {} inputs disagree: {}",
+            disagreeing.len(),
+            runs.len(),
+            disagreeing.join(", ")
+        );
+        return Err(AocError::new(AocErrorType::WrongAnswers(disagreeing.len())).into());
+    }
+    Ok(())
+}
+
+fn run(
+    registry: &Registry,
+    days: &DaySelection,
+    options: &RunOptions,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(directory) = &options.inputs {
+        return match *days {
+            DaySelection::Single(day) => run_directory(registry, day, directory, &options.settings),
+            DaySelection::All => {
+                Err(AocError::new(AocErrorType::InvalidArgument("all".to_string())).into())
+            }
+        };
+    }
+
+    match *days {
+        DaySelection::Single(day) => {
+            measure_day(registry, day, &options.settings)?.print(&SubmissionLog::load(day)?);
+            Ok(())
+        }
+        DaySelection::All => {
+            let mut reports = vec![];
+            for (day, _) in registry.days() {
+                println!("Day {}", day);
+                // A missing input for one day shouldn't stop us from running the others
+                let result = measure_day(registry, day, &options.settings)
+                    .and_then(|report| Ok((SubmissionLog::load(day)?, report)));
+                match result {
+                    Ok((log, report)) => {
+                        report.print(&log);
+                        reports.push(report);
+                    }
+                    Err(error) => println!("{}", error),
+                }
+            }
+            println!();
+            print!("{}", summary_table(&reports));
+            Ok(())
+        }
+    }
+}
+
+/// Check the answers of the selected days against their known answers and fail
+/// when any of them doesn't match
+fn verify(
+    registry: &Registry,
+    days: &DaySelection,
+    settings: &RunSettings,
+) -> Result<(), Box<dyn Error>> {
+    let days: Vec<u32> = match days {
+        DaySelection::All => registry.days().map(|(day, _)| day).collect(),
+        DaySelection::Single(day) => vec![*day],
+    };
+
+    let mut failures = 0;
+    for day in days {
+        let report = match measure_day(registry, day, settings) {
+            Ok(report) => report,
+            // Days that haven't been downloaded yet have nothing to verify
+            Err(error) if is_missing_input(error.as_ref()) => {
+                println!("Day {}: missing ({})", day, error);
+                continue;
+            }
+            Err(error) => {
+                println!("Day {}: FAIL ({})", day, error);
+                failures += 1;
+                continue;
+            }
+        };
+        let known = KnownAnswers::load(day)?;
+        for verdict in known.verify(&report) {
+            if matches!(verdict.verdict, Verdict::Fail { .. }) {
+                failures += 1;
+            }
+            println!("Day {} {}", day, verdict);
+        }
+    }
+
+    if failures > 0 {
+        return Err(AocError::new(AocErrorType::WrongAnswers(failures)).into());
+    }
+    Ok(())
+}
+
+fn is_missing_input(error: &(dyn Error + 'static)) -> bool {
+    error
+        .downcast_ref::<AocError>()
+        .is_some_and(|error| matches!(error.error_type(), AocErrorType::InputDataNotFound))
+}
+
+/// Print the parameters a day declares, with their defaults
+fn list_parameters(registry: &Registry, day: u32) -> Result<(), Box<dyn Error>> {
+    let puzzle = registry
+        .get(day)
+        .ok_or(AocError::new(AocErrorType::UnknownDay(day)))?;
+    let parameters = puzzle.parameters();
+    if parameters.is_empty() {
+        println!("Day {} has no parameters", day);
+    }
+    for (name, value) in parameters {
+        println!("{} = {}", name, value);
+    }
+    Ok(())
+}
+
+/// Download the inputs of the selected days that aren't on disk yet
+fn fetch(registry: &Registry, days: &DaySelection) -> Result<(), Box<dyn Error>> {
+    let days: Vec<u32> = match days {
+        DaySelection::All => registry.days().map(|(day, _)| day).collect(),
+        DaySelection::Single(day) => vec![*day],
+    };
+
+    // Inputs that are already on disk don't need a session
+    let (cached, missing): (Vec<u32>, Vec<u32>) =
+        days.into_iter().partition(|&day| input_path(day).exists());
+    for day in cached {
+        println!("Day {}: already in {}", day, input_path(day).display());
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let client = AocClient::new(&Config::load()?)?;
+    for day in missing {
+        let path = input_path(day);
+        if fetch_input(&client, day, &path)? {
+            println!("Day {}: downloaded to {}", day, path.display());
+        } else {
+            println!("Day {}: already in {}", day, path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Submit the answer of one part and remember what the website said about it
+fn submit(
+    registry: &Registry,
+    day: u32,
+    part: Part,
+    answer: Option<&String>,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let client = AocClient::new(&Config::load()?)?;
+    let answer = match answer {
+        Some(answer) => match answer.parse::<i64>() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(answer.clone()),
+        },
+        None => {
+            let settings = RunSettings {
+                parts: vec![part],
+                ..RunSettings::default()
+            };
+            let report = measure_day(registry, day, &settings)?;
+            report.parts[0].answer.clone()?
+        }
+    };
+
+    let mut log = SubmissionLog::load(day)?;
+    if let Some(warning) = log.check(part, &answer) {
+        println!("Warning: {}", warning);
+        if !force {
+            println!("Not submitting {}, use --force to submit anyway", answer);
+            return Ok(());
+        }
+    }
+
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let outcome = client.submit_answer(day, part, &answer)?;
+    println!("The answer is {}", outcome);
+
+    log.add(Submission {
+        part,
+        answer: answer.clone(),
+        outcome: outcome.clone(),
+    });
+    log.save(day)?;
+
+    if outcome == Outcome::Correct {
+        let mut known = KnownAnswers::load(day)?;
+        known.insert(part, answer);
+        known.save(day)?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // Print errors readably, the default would show their debug form
+    match run_command(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_command(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args.first().map(String::as_str) {
+        Some("run") => {
+            let options = parse_run_options(&args[1..]).inspect_err(|_| eprintln!("{}", USAGE))?;
+            let days = options.days.as_ref().ok_or_else(|| {
+                eprintln!("{}", USAGE);
+                AocError::new(AocErrorType::MissingArgument)
+            })?;
+            run(&registry(), days, &options)
+        }
+        Some("verify") => {
+            let options = parse_run_options(&args[1..]).inspect_err(|_| eprintln!("{}", USAGE))?;
+            let days = options.days.unwrap_or(DaySelection::All);
+            verify(&registry(), &days, &options.settings)
+        }
+        Some("params") => match args.get(1).map(|arg| DaySelection::parse(arg)) {
+            Some(Ok(DaySelection::Single(day))) => list_parameters(&registry(), day),
+            _ => {
+                eprintln!("{}", USAGE);
+                Err(AocError::new(AocErrorType::MissingArgument).into())
+            }
+        },
+        Some("fetch") => {
+            let days = args
+                .get(1)
+                .ok_or(AocError::new(AocErrorType::MissingArgument))
+                .and_then(|arg| DaySelection::parse(arg))
+                .inspect_err(|_| eprintln!("{}", USAGE))?;
+            fetch(&registry(), &days)
+        }
+        Some("submit") => {
+            let force = args.iter().any(|arg| arg == "--force");
+            let args: Vec<&String> = args.iter().filter(|arg| *arg != "--force").collect();
+            let (day, part) = match (args.get(1), args.get(2)) {
+                (Some(day), Some(part)) => (DaySelection::parse(day), part.parse::<Part>()),
+                _ => {
+                    eprintln!("{}", USAGE);
+                    return Err(AocError::new(AocErrorType::MissingArgument).into());
+                }
+            };
+            match (day.inspect_err(|_| eprintln!("{}", USAGE))?, part?) {
+                (DaySelection::Single(day), part) => {
+                    submit(&registry(), day, part, args.get(3).copied(), force)
+                }
+                (DaySelection::All, _) => {
+                    eprintln!("{}", USAGE);
+                    Err(AocError::new(AocErrorType::InvalidArgument("all".to_string())).into())
+                }
+            }
+        }
+        Some(command) => {
+            eprintln!("{}", USAGE);
+            Err(AocError::new(AocErrorType::InvalidArgument(command.to_string())).into())
+        }
+        None => {
+            eprintln!("{}", USAGE);
+            Err(AocError::new(AocErrorType::MissingArgument).into())
+        }
+    }
 }
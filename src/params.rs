@@ -0,0 +1,157 @@
+// Puzzle parameters, like the grid size of day14, that can be changed without
+// touching the code. Every input can come with a `<name>.params.toml` next to
+// it, e.g. `input_data/day11/input.params.toml`:
+//
+//     blinks_part2 = 100
+//
+// and the runner accepts `--param blinks_part2=100`, which wins over the file.
+
+use crate::{AocError, AocErrorType};
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_path() {
+        assert_eq!(
+            params_path(Path::new("input_data/day14/input.txt")),
+            PathBuf::from("input_data/day14/input.params.toml")
+        );
+    }
+
+    #[test]
+    fn test_parse_parameters() {
+        let table: Table = "width = 11\nheight = 7".parse().unwrap();
+        let parameters = parse_parameters(table).unwrap();
+
+        assert_eq!(parameters["width"], 11);
+        assert_eq!(parameters["height"], 7);
+
+        let table: Table = "width = \"wide\"".parse().unwrap();
+        assert!(parse_parameters(table).is_err());
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("blinks_part2=100").unwrap(),
+            ("blinks_part2".to_string(), 100)
+        );
+        assert!(parse_assignment("blinks_part2").is_err());
+        assert!(parse_assignment("blinks_part2=many").is_err());
+    }
+
+    #[test]
+    fn test_parameter_values() {
+        assert_eq!(positive("width", 11).unwrap(), 11);
+        assert!(positive("width", 0).is_err());
+        assert!(positive("width", -11).is_err());
+        assert!(positive("width", i64::from(i32::MAX) + 1).is_err());
+
+        assert_eq!(non_negative("fallen_bytes", 0).unwrap(), 0);
+        assert!(non_negative("fallen_bytes", -5).is_err());
+
+        assert_eq!(in_range("prize_offset", 10, 0..=100).unwrap(), 10);
+        assert!(in_range("prize_offset", i64::MAX, 0..=100).is_err());
+    }
+}
+
+pub type Parameters = BTreeMap<String, i64>;
+
+/// The location of the parameters that belong to the given input file
+pub fn params_path(input: &Path) -> PathBuf {
+    input.with_extension("params.toml")
+}
+
+pub fn parse_parameters(table: Table) -> Result<Parameters, AocError> {
+    table
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::Integer(value) => Ok((name, value)),
+            _ => Err(AocError::new(AocErrorType::InvalidParameter(format!(
+                "parameter '{}' must be a number",
+                name
+            )))),
+        })
+        .collect()
+}
+
+/// Load parameters from a file, a missing file means no parameters
+pub fn load_parameters(path: &Path) -> Result<Parameters, AocError> {
+    let invalid = |reason: String| {
+        AocError::new(AocErrorType::InvalidParameter(format!(
+            "{}: {}",
+            path.display(),
+            reason
+        )))
+    };
+
+    let source = match read_to_string(path) {
+        Ok(source) => source,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Parameters::new()),
+        Err(error) => return Err(invalid(error.to_string())),
+    };
+    let table: Table = source
+        .parse()
+        .map_err(|e: toml::de::Error| invalid(e.to_string()))?;
+    parse_parameters(table)
+}
+
+/// Parse a `name=value` assignment from the command line
+pub fn parse_assignment(arg: &str) -> Result<(String, i64), AocError> {
+    arg.split_once('=')
+        .and_then(|(name, value)| Some((name.trim().to_string(), value.trim().parse().ok()?)))
+        .ok_or(AocError::new(AocErrorType::InvalidParameter(
+            arg.to_string(),
+        )))
+}
+
+/// The error for a parameter that the puzzle doesn't have
+pub fn unknown_parameter(name: &str) -> AocError {
+    AocError::new(AocErrorType::UnknownParameter(name.to_string()))
+}
+
+/// A parameter that counts or measures something, like the width of a grid,
+/// which has to be a positive `i32`
+pub fn positive(name: &str, value: i64) -> Result<i32, AocError> {
+    i32::try_from(value)
+        .ok()
+        .filter(|value| *value > 0)
+        .ok_or(AocError::new(AocErrorType::InvalidParameter(format!(
+            "'{}' must be a positive number, not {}",
+            name, value
+        ))))
+}
+
+/// A parameter that can be zero but not negative, like an offset
+pub fn non_negative(name: &str, value: i64) -> Result<i64, AocError> {
+    match value {
+        0.. => Ok(value),
+        _ => Err(AocError::new(AocErrorType::InvalidParameter(format!(
+            "'{}' must not be negative, not {}",
+            name, value
+        )))),
+    }
+}
+
+/// A parameter that has to stay within the range, like an offset that would
+/// make the arithmetic of the puzzle overflow when it gets too large
+pub fn in_range(name: &str, value: i64, range: RangeInclusive<i64>) -> Result<i64, AocError> {
+    match range.contains(&value) {
+        true => Ok(value),
+        false => Err(AocError::new(AocErrorType::InvalidParameter(format!(
+            "'{}' must be between {} and {}, not {}",
+            name,
+            range.start(),
+            range.end(),
+            value
+        )))),
+    }
+}
@@ -0,0 +1,601 @@
+// Errors for malformed puzzle input. A `ParseError` knows the line and column
+// of the text it couldn't make sense of and can show it like a compiler does:
+//
+//     line 3, column 5: expected a number, found "abc"
+//       |
+//     3 | 12  abc
+//       |     ^^^
+//
+// The parsers at the end of this module describe the format of a line by
+// putting small parsers together, so that they can point at the exact spot
+// where a line doesn't fit:
+//
+//     // p=0,4 v=3,-3
+//     let vector = || pair(terminated(signed_int(), literal(",")), signed_int());
+//     let robot = pair(key_value("p", vector()), preceded(whitespace(), key_value("v", vector())));
+//     let ((px, py), (vx, vy)): ((i64, i64), (i64, i64)) = parse_line(line, robot)?;
+
+use crate::{Location, Location3, Position};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SimpleChar, SimpleParse};
+
+    #[test]
+    fn test_error_of_a_token() {
+        let error = "12x".try_i32().unwrap_err();
+
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "12x");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a digit")]
+    fn test_digit_of_a_non_digit() {
+        assert_eq!('7'.as_i32(), 7);
+        '.'.as_i32();
+    }
+
+    #[test]
+    fn test_parse_lines_reports_the_position() {
+        let error = parse_lines("1   2\n3   4\n5   six\n", |line| line.try_pair()).unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "six");
+        assert_eq!(error.source_line, "5   six");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1   2\n3   4\n\n", |line| line.try_pair()).unwrap(),
+            vec![(1, 2), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn test_error_in_several_lines() {
+        let error = ParseError::new("1,2\r\n3,x\r\n5,6", 7, "x", "expected a number");
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.source_line, "3,x");
+    }
+
+    #[test]
+    fn test_render() {
+        let error = parse_lines("12, 7\n13, abc", |line| {
+            let (left, right) = line.split_once(", ").unwrap();
+            Ok((left.try_i32()?, right.try_i32()?))
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error.render(),
+            "line 2, column 5: expected a number, found \"abc\"\n  |\n2 | 13, abc\n  |     ^^^"
+        );
+    }
+
+    #[test]
+    fn test_signed_int() {
+        assert_eq!(parse_line("42", signed_int::<i32>()).unwrap(), 42);
+        assert_eq!(parse_line("-42", signed_int::<i64>()).unwrap(), -42);
+        assert_eq!(parse_line("+42", signed_int::<i64>()).unwrap(), 42);
+        assert!(parse_line("-", signed_int::<i64>()).is_err());
+        assert!(parse_line("99999999999", signed_int::<i32>()).is_err());
+    }
+
+    #[test]
+    fn test_key_value_pairs() {
+        let vector = || pair(terminated(signed_int(), literal(",")), signed_int());
+        let robot = pair(
+            key_value("p", vector()),
+            preceded(whitespace(), key_value("v", vector())),
+        );
+
+        assert_eq!(
+            parse_line("p=0,4 v=3,-3", robot).unwrap(),
+            ((0i64, 4i64), (3i64, -3i64))
+        );
+    }
+
+    #[test]
+    fn test_separated_list() {
+        let program = preceded(
+            literal("Program: "),
+            separated(signed_int::<i32>(), literal(",")),
+        );
+
+        assert_eq!(
+            parse_line("Program: 0,1,5,4", &program).unwrap(),
+            vec![0, 1, 5, 4]
+        );
+        let error = parse_line("Program: 0,1,,4", &program).unwrap_err();
+        assert_eq!(error.column, 14);
+        assert_eq!(error.message, "expected a number");
+    }
+
+    #[test]
+    fn test_optional() {
+        let number = pair(optional(literal("#")), signed_int::<i32>());
+
+        assert_eq!(parse_line("#12", &number).unwrap(), (Some(()), 12));
+        assert_eq!(parse_line("12", &number).unwrap(), (None, 12));
+    }
+
+    #[derive(FromLine, Debug, PartialEq)]
+    #[aoc(pattern = "{}: {...}")]
+    struct Equation {
+        test: i64,
+        numbers: Vec<i64>,
+    }
+
+    #[derive(FromLine, Debug, PartialEq)]
+    #[aoc(pattern = "p={},{} v={},{}")]
+    struct Robot(Position, Location);
+
+    #[test]
+    fn test_derive_from_line() {
+        assert_eq!(
+            "190: 10 19".parse::<Equation>().unwrap(),
+            Equation {
+                test: 190,
+                numbers: vec![10, 19]
+            }
+        );
+        assert_eq!(
+            Robot::from_line("p=0,4 v=3,-3").unwrap(),
+            Robot(Position { x: 0, y: 4 }, Location { column: 3, row: -3 })
+        );
+    }
+
+    #[test]
+    fn test_derive_from_line_errors() {
+        let error = Equation::from_line("190: 10 1x9").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (9, "1x9"));
+
+        let error = Equation::from_line("190 10 19").unwrap_err();
+        assert_eq!(error.message, "expected \": \"");
+
+        let error = Robot::from_line("p=0,4 v=3").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (10, "expected \",\"")
+        );
+
+        let error = Robot::from_line("p=0,4 w=3,-3").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (6, "expected \" v=\"")
+        );
+    }
+
+    #[test]
+    fn test_whole_line_is_parsed() {
+        let error = parse_line("12 apples", signed_int::<i32>()).unwrap_err();
+
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, " apples");
+        assert_eq!(error.message, "expected the end of the line");
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column within the line in characters, starting at 1
+    pub column: usize,
+    /// The text that couldn't be parsed
+    pub text: String,
+    /// The whole line the text was found in
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which starts at the given byte offset of
+    /// `source`. The source may have several lines.
+    pub fn new(source: &str, offset: usize, text: &str, message: &str) -> ParseError {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |newline| offset + newline);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Place an error found in a part of a line into the whole line, which is
+    /// line `number` of the input. The part is looked up by its text, so the
+    /// column belongs to its first occurrence.
+    pub fn on_line(mut self, number: usize, line: &str) -> ParseError {
+        if let Some(offset) = line.find(&self.source_line) {
+            self.column += line[..offset].chars().count();
+        }
+        self.line = number;
+        self.source_line = line.to_string();
+        self
+    }
+
+    /// Show the error together with the line of the input and a caret under
+    /// the offending text
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            margin,
+            number,
+            self.source_line,
+            margin,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}, found \"{}\"",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse every line of the input, errors get the number of the line they
+/// were found in. Empty lines at the end of the input are ignored.
+pub fn parse_lines<T>(
+    input: &str,
+    parse_line: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|error| error.on_line(index + 1, line)))
+        .collect()
+}
+
+// ------------------------------------------------------------------
+// Parser combinators
+
+/// What a parser expected at a byte offset of the line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub offset: usize,
+    pub what: String,
+}
+
+impl Expected {
+    /// The error for the line this was expected in
+    pub fn in_line(&self, line: &str) -> ParseError {
+        // Show the text up to the next separator, or the rest of the line when
+        // it doesn't start with a word or a number
+        let rest = &line[self.offset..];
+        let length = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '+'))
+            .filter(|length| *length > 0)
+            .unwrap_or(rest.len());
+        ParseError::new(
+            line,
+            self.offset,
+            &rest[..length],
+            &format!("expected {}", self.what),
+        )
+    }
+}
+
+/// The value and the offset right behind it, or what was expected instead
+pub type Step<T> = Result<(T, usize), Expected>;
+
+/// A parser gets the whole line and the byte offset where it should start.
+/// This is implemented for all functions and closures of the right type.
+pub trait Parser<T>: Fn(&str, usize) -> Step<T> {}
+
+impl<T, F: Fn(&str, usize) -> Step<T>> Parser<T> for F {}
+
+fn expected<T>(offset: usize, what: &str) -> Step<T> {
+    Err(Expected {
+        offset,
+        what: what.to_string(),
+    })
+}
+
+/// Parse a whole line with the given parser
+pub fn parse_line<T>(line: &str, parser: impl Parser<T>) -> Result<T, ParseError> {
+    let failure = match parser(line, 0) {
+        Ok((value, end)) if end == line.len() => return Ok(value),
+        Ok((_, end)) => Expected {
+            offset: end,
+            what: "the end of the line".to_string(),
+        },
+        Err(failure) => failure,
+    };
+    Err(failure.in_line(line))
+}
+
+/// Exactly the given text
+pub fn literal(text: &'static str) -> impl Parser<()> {
+    move |line: &str, offset: usize| {
+        if line[offset..].starts_with(text) {
+            Ok(((), offset + text.len()))
+        } else {
+            expected(offset, &format!("\"{}\"", text))
+        }
+    }
+}
+
+/// Any amount of spaces and tabs, including none
+pub fn whitespace() -> impl Parser<()> {
+    |line: &str, offset: usize| {
+        let rest = &line[offset..];
+        let skipped = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        Ok(((), offset + skipped))
+    }
+}
+
+/// A decimal number with an optional sign
+pub fn signed_int<T: FromStr>() -> impl Parser<T> {
+    |line: &str, offset: usize| {
+        let rest = &line[offset..];
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return expected(offset, "a number");
+        }
+        let end = offset + sign + digits;
+        match line[offset..end].parse() {
+            Ok(value) => Ok((value, end)),
+            Err(_) => expected(offset, "a smaller number"),
+        }
+    }
+}
+
+/// `first` followed by `second`
+pub fn pair<A, B>(first: impl Parser<A>, second: impl Parser<B>) -> impl Parser<(A, B)> {
+    move |line: &str, offset: usize| {
+        let (a, offset) = first(line, offset)?;
+        let (b, offset) = second(line, offset)?;
+        Ok(((a, b), offset))
+    }
+}
+
+/// The value of `parser`, after skipping over `prefix`
+pub fn preceded<P, T>(prefix: impl Parser<P>, parser: impl Parser<T>) -> impl Parser<T> {
+    move |line: &str, offset: usize| {
+        let (_, offset) = prefix(line, offset)?;
+        parser(line, offset)
+    }
+}
+
+/// The value of `parser`, which has to be followed by `suffix`
+pub fn terminated<T, S>(parser: impl Parser<T>, suffix: impl Parser<S>) -> impl Parser<T> {
+    move |line: &str, offset: usize| {
+        let (value, offset) = parser(line, offset)?;
+        let (_, offset) = suffix(line, offset)?;
+        Ok((value, offset))
+    }
+}
+
+/// Turn the value of `parser` into something else
+pub fn map<T, U>(parser: impl Parser<T>, f: impl Fn(T) -> U) -> impl Parser<U> {
+    move |line: &str, offset: usize| {
+        let (value, offset) = parser(line, offset)?;
+        Ok((f(value), offset))
+    }
+}
+
+/// The value of `parser`, or `None` without consuming anything when it
+/// doesn't match
+pub fn optional<T>(parser: impl Parser<T>) -> impl Parser<Option<T>> {
+    move |line: &str, offset: usize| match parser(line, offset) {
+        Ok((value, end)) => Ok((Some(value), end)),
+        Err(_) => Ok((None, offset)),
+    }
+}
+
+/// One or more items with a `separator` between them
+pub fn separated<T, S>(item: impl Parser<T>, separator: impl Parser<S>) -> impl Parser<Vec<T>> {
+    move |line: &str, offset: usize| {
+        let (first, mut offset) = item(line, offset)?;
+        let mut items = vec![first];
+        while let Ok((_, next)) = separator(line, offset) {
+            let (value, end) = item(line, next)?;
+            items.push(value);
+            offset = end;
+        }
+        Ok((items, offset))
+    }
+}
+
+/// The value of `key=value` for the given key
+pub fn key_value<T>(key: &'static str, value: impl Parser<T>) -> impl Parser<T> {
+    move |line: &str, offset: usize| {
+        let (_, offset) = literal(key)(line, offset)?;
+        let (_, offset) = literal("=")(line, offset)?;
+        value(line, offset)
+    }
+}
+
+// ------------------------------------------------------------------
+// Line patterns, the runtime part of `#[derive(FromLine)]`
+
+pub use aoc2024_derive::FromLine;
+
+/// Types that can be created from a single line of the input
+pub trait FromLine: Sized {
+    fn from_line(line: &str) -> Result<Self, ParseError>;
+}
+
+/// A piece of a line pattern, `{}` is a `Value` and `{...}` the `Rest`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    Literal(&'static str),
+    Value,
+    Rest,
+}
+
+/// The text of a line that took the place of a `{}` or `{...}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capture<'a> {
+    /// Byte offset of the text in the line
+    pub offset: usize,
+    pub text: &'a str,
+}
+
+/// Match a line against a pattern and return the text of all placeholders.
+/// A `{}` extends up to the text that follows it in the pattern.
+pub fn match_pattern<'a>(
+    line: &'a str,
+    pattern: &[Segment],
+) -> Result<Vec<Capture<'a>>, ParseError> {
+    let fail = |offset: usize, what: String| Expected { offset, what }.in_line(line);
+
+    let mut captures = vec![];
+    let mut offset = 0;
+    for (index, segment) in pattern.iter().enumerate() {
+        let rest = &line[offset..];
+        match segment {
+            Segment::Literal(text) => {
+                if !rest.starts_with(text) {
+                    return Err(fail(offset, format!("\"{}\"", text)));
+                }
+                offset += text.len();
+            }
+            Segment::Value => {
+                let length = match pattern.get(index + 1) {
+                    Some(Segment::Literal(next)) => rest.find(next).ok_or_else(|| {
+                        let value = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                        fail(offset + value, format!("\"{}\"", next))
+                    })?,
+                    _ => rest.len(),
+                };
+                if length == 0 {
+                    return Err(fail(offset, "a value".to_string()));
+                }
+                captures.push(Capture {
+                    offset,
+                    text: &rest[..length],
+                });
+                offset += length;
+            }
+            Segment::Rest => {
+                captures.push(Capture { offset, text: rest });
+                offset = line.len();
+            }
+        }
+    }
+    if offset != line.len() {
+        return Err(fail(offset, "the end of the line".to_string()));
+    }
+    Ok(captures)
+}
+
+/// Types that can be built from the placeholders of a line pattern, which is
+/// what the fields of a `#[derive(FromLine)]` struct need
+pub trait FromCaptures: Sized {
+    /// How many placeholders the type takes
+    const CAPTURES: usize;
+
+    fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError>;
+}
+
+macro_rules! numbers_from_captures {
+    ($($number:ty),*) => {
+        $(
+            impl FromCaptures for $number {
+                const CAPTURES: usize = 1;
+
+                fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+                    let capture = captures[0];
+                    capture.text.parse().map_err(|_| {
+                        ParseError::new(line, capture.offset, capture.text, "expected a number")
+                    })
+                }
+            }
+        )*
+    };
+}
+
+numbers_from_captures!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromCaptures for String {
+    const CAPTURES: usize = 1;
+
+    fn from_captures(_line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+        Ok(captures[0].text.to_string())
+    }
+}
+
+impl FromCaptures for Position {
+    const CAPTURES: usize = 2;
+
+    fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+        Ok(Position {
+            x: i64::from_captures(line, &captures[0..1])?,
+            y: i64::from_captures(line, &captures[1..2])?,
+        })
+    }
+}
+
+impl FromCaptures for Location {
+    const CAPTURES: usize = 2;
+
+    fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+        Ok(Location {
+            column: i32::from_captures(line, &captures[0..1])?,
+            row: i32::from_captures(line, &captures[1..2])?,
+        })
+    }
+}
+
+impl FromCaptures for Location3 {
+    const CAPTURES: usize = 3;
+
+    fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+        Ok(Location3 {
+            x: i32::from_captures(line, &captures[0..1])?,
+            y: i32::from_captures(line, &captures[1..2])?,
+            z: i32::from_captures(line, &captures[2..3])?,
+        })
+    }
+}
+
+/// The whitespace separated values of a `{...}`
+impl<T: FromCaptures> FromCaptures for Vec<T> {
+    const CAPTURES: usize = 1;
+
+    fn from_captures(line: &str, captures: &[Capture]) -> Result<Self, ParseError> {
+        let capture = captures[0];
+        capture
+            .text
+            .split_whitespace()
+            .map(|text| {
+                // The value is a part of the capture, so this is its position
+                let offset =
+                    capture.offset + (text.as_ptr() as usize - capture.text.as_ptr() as usize);
+                T::from_captures(line, &[Capture { offset, text }])
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,118 @@
+// Reading the usual layout of a puzzle input: lines, and sometimes several
+// sections of lines with an empty line between them, like the rules and the
+// updates of day5. Windows line endings and empty lines at the end of the
+// input, which a downloaded input often has, are ignored.
+
+use crate::parse::ParseError;
+use crate::SimpleParse;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let reader = InputReader::new("47|53\r\n97|13\r\n\r\n75,47,61\r\n97,61\r\n\r\n\r\n");
+
+        assert_eq!(reader.sections(), vec!["47|53\n97|13", "75,47,61\n97,61"]);
+        assert_eq!(reader.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_two_sections() {
+        let reader = InputReader::new("r, wr, b\n\nbrwrr\nbggr\n");
+        assert_eq!(reader.two_sections().unwrap(), ("r, wr, b", "brwrr\nbggr"));
+
+        let error = InputReader::new("r, wr, b\nbrwrr\n")
+            .two_sections()
+            .unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!("p=0,4 v=3,-3".ints().unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!("Button A: X+94, Y+34".ints_i64().unwrap(), vec![94, 34]);
+        // A dash between numbers separates them
+        assert_eq!("1-3 a: abcde".ints().unwrap(), vec![1, 3]);
+        assert!("no numbers".ints().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ints_too_large() {
+        let reader = InputReader::new("X+94\nY=10000000000000, Z=3\n");
+        let error = reader.ints().unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "10000000000000");
+        assert_eq!(reader.ints_i64().unwrap(), vec![94, 10000000000000, 3]);
+    }
+}
+
+/// The text of a puzzle input with `\n` line endings and without empty lines
+/// at the end
+#[derive(Debug, Clone)]
+pub struct InputReader {
+    text: String,
+}
+
+impl InputReader {
+    pub fn new(input: &str) -> InputReader {
+        InputReader {
+            text: input.replace("\r\n", "\n").trim_end().to_string(),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> + '_ {
+        self.text.lines()
+    }
+
+    /// The blocks of lines between empty lines. Lines with only whitespace
+    /// count as empty, several empty lines in a row separate just two blocks.
+    pub fn sections(&self) -> Vec<&str> {
+        let mut sections = vec![];
+        let mut start = None;
+        let mut offset = 0;
+        for line in self.text.split('\n') {
+            if line.trim().is_empty() {
+                if let Some(start) = start.take() {
+                    // Without the newline in front of the empty line
+                    sections.push(&self.text[start..offset - 1]);
+                }
+            } else if start.is_none() {
+                start = Some(offset);
+            }
+            offset += line.len() + 1;
+        }
+        if let Some(start) = start {
+            sections.push(&self.text[start..]);
+        }
+        sections
+    }
+
+    /// The two sections of an input like "towels, empty line, designs"
+    pub fn two_sections(&self) -> Result<(&str, &str), ParseError> {
+        match self.sections()[..] {
+            [first, second] => Ok((first, second)),
+            _ => Err(ParseError::new(
+                &self.text,
+                self.text.len(),
+                "",
+                "expected two sections separated by an empty line",
+            )),
+        }
+    }
+
+    /// All numbers of the input, see `SimpleParse::ints`
+    pub fn ints(&self) -> Result<Vec<i32>, ParseError> {
+        self.text.ints()
+    }
+
+    pub fn ints_i64(&self) -> Result<Vec<i64>, ParseError> {
+        self.text.ints_i64()
+    }
+}
@@ -0,0 +1,439 @@
+// Running the days on their puzzle input while measuring how long parsing and
+// each of the parts take.
+
+use crate::cancel::CancellationToken;
+use crate::params::{load_parameters, params_path, Parameters};
+use crate::submissions::SubmissionLog;
+use crate::{Answer, AocError, AocErrorType, Part, Puzzle, Registry};
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SimpleParse, Solution};
+
+    fn timing_of(millis: &[u64]) -> Timing {
+        let mut timing = Timing::default();
+        for m in millis {
+            timing.add(Duration::from_millis(*m));
+        }
+        timing
+    }
+
+    #[test]
+    fn test_timing_statistics() {
+        let timing = timing_of(&[7, 3, 5, 9, 4]);
+
+        assert_eq!(timing.min(), Duration::from_millis(3));
+        assert_eq!(timing.median(), Duration::from_millis(5));
+        assert_eq!(timing.max(), Duration::from_millis(9));
+    }
+
+    #[test]
+    fn test_timing_of_a_single_run_shows_only_one_duration() {
+        assert_eq!(timing_of(&[12]).to_string(), "12.00ms");
+        assert_eq!(
+            timing_of(&[1, 2, 3]).to_string(),
+            "min 1.00ms, median 2.00ms, max 3.00ms"
+        );
+    }
+
+    #[test]
+    fn test_formatting_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(870)), "870ns");
+        assert_eq!(format_duration(Duration::from_micros(42)), "42.00µs");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2345)), "2.35s");
+    }
+
+    #[derive(Clone)]
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        type Input = i64;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.trim().try_i64()?)
+        }
+
+        // Sleeps for the given number of milliseconds
+        fn part1(&self, millis: &Self::Input) -> Answer {
+            let millis = u64::try_from(*millis).expect("Can't sleep for a negative time");
+            thread::sleep(Duration::from_millis(millis));
+            Answer::Number(1)
+        }
+
+        // Waits until cancelled
+        fn part2(&self, _: &Self::Input) -> Answer {
+            let token = CancellationToken::current();
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Answer::Number(2)
+        }
+    }
+
+    #[test]
+    fn test_parts_that_take_too_long_time_out() {
+        let settings = RunSettings {
+            timeout: Some(Duration::from_millis(200)),
+            ..RunSettings::default()
+        };
+
+        let report = measure(0, &Sleepy, "1", &settings).unwrap();
+
+        assert_eq!(report.parts[0].answer, Ok(Answer::Number(1)));
+        assert_eq!(report.parts[1].answer, Err(PartFailure::TimedOut));
+    }
+
+    #[test]
+    fn test_panicking_parts_are_reported() {
+        let settings = RunSettings {
+            parts: vec![Part::One],
+            ..RunSettings::default()
+        };
+
+        let report = measure(0, &Sleepy, "-1", &settings).unwrap();
+
+        assert_eq!(report.parts[0].answer, Err(PartFailure::Panicked));
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let report = DayReport {
+            day: 3,
+            parse: timing_of(&[1]),
+            parts: vec![PartReport {
+                part: Part::Two,
+                answer: Ok(Answer::Number(48)),
+                timing: timing_of(&[2]),
+            }],
+        };
+
+        assert_eq!(
+            summary_table(&[report]),
+            "Day |    Parse |   Part 1 |   Part 2 |    Total\n\
+             ----+----------+----------+----------+---------\n  \
+               3 |   1.00ms |        - |   2.00ms |   3.00ms\n"
+        );
+    }
+}
+
+/// Format a duration with a unit that fits its magnitude
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// The measured durations of repeatedly running the same piece of code
+#[derive(Debug, Clone, Default)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn add(&mut self, duration: Duration) {
+        self.samples.push(duration);
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.samples.len() <= 1 {
+            write!(f, "{}", format_duration(self.median()))
+        } else {
+            write!(
+                f,
+                "min {}, median {}, max {}",
+                format_duration(self.min()),
+                format_duration(self.median()),
+                format_duration(self.max())
+            )
+        }
+    }
+}
+
+/// Why a part didn't produce an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartFailure {
+    TimedOut,
+    Panicked,
+}
+
+impl fmt::Display for PartFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartFailure::TimedOut => write!(f, "timed out"),
+            PartFailure::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+impl Error for PartFailure {}
+
+pub type PartResult = Result<Answer, PartFailure>;
+
+/// The answer of a part, or why there is none
+pub fn describe(result: &PartResult) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(failure) => failure.to_string(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: PartResult,
+    pub timing: Timing,
+}
+
+/// Answers and timings from running one day
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u32,
+    pub parse: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn part(&self, part: Part) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    /// The time for parsing and running all parts, based on the medians
+    pub fn total(&self) -> Duration {
+        self.parse.median()
+            + self
+                .parts
+                .iter()
+                .map(|report| report.timing.median())
+                .sum::<Duration>()
+    }
+
+    /// Print the answers with their timings, warning about answers that the
+    /// earlier submissions in `log` already rule out
+    pub fn print(&self, log: &SubmissionLog) {
+        println!("Parsing: {}", self.parse);
+        for report in &self.parts {
+            println!(
+                "Answer part {}: {} ({})",
+                report.part,
+                describe(&report.answer),
+                report.timing
+            );
+            if let Some(warning) = report
+                .answer
+                .as_ref()
+                .ok()
+                .and_then(|answer| log.check(report.part, answer))
+            {
+                println!("  Warning: {}", warning);
+            }
+        }
+    }
+}
+
+/// How to run the parts of a day
+#[derive(Debug, Clone)]
+pub struct RunSettings {
+    pub parts: Vec<Part>,
+    /// How often everything is run to get more reliable timings
+    pub repetitions: usize,
+    pub parameters: Parameters,
+    /// The time budget of every part, without one a part may run forever
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RunSettings {
+    fn default() -> Self {
+        RunSettings {
+            parts: Part::BOTH.to_vec(),
+            repetitions: 1,
+            parameters: Parameters::new(),
+            timeout: None,
+        }
+    }
+}
+
+// The parts run on their own thread, give them the same room for recursion
+// they would have on the main thread and then some
+const PART_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Solve a part on a separate thread and wait for it at most `timeout`. When
+/// the time is up the part's cancellation token is cancelled, but the thread
+/// is left running if the part doesn't check the token.
+fn solve_with_watchdog(
+    puzzle: Arc<dyn Puzzle>,
+    input: Arc<dyn Any + Send + Sync>,
+    part: Part,
+    timeout: Option<Duration>,
+) -> (PartResult, Duration) {
+    let token = CancellationToken::new();
+    let (sender, receiver) = channel();
+    let start = Instant::now();
+
+    let part_token = token.clone();
+    thread::Builder::new()
+        .name(format!("part {}", part))
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            part_token.make_current();
+            let start = Instant::now();
+            let answer = puzzle.solve(part, input.as_ref());
+            // Nobody listens anymore when the part took too long
+            let _ = sender.send((answer, start.elapsed()));
+        })
+        .expect("Couldn't start a thread for the part");
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok((answer, duration)) => (Ok(answer), duration),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            (Err(PartFailure::TimedOut), start.elapsed())
+        }
+        Err(RecvTimeoutError::Disconnected) => (Err(PartFailure::Panicked), start.elapsed()),
+    }
+}
+
+/// Parse the input and solve the requested parts `repetitions` times, measuring
+/// every step separately. The answers are taken from the last repetition, a
+/// part that timed out or panicked isn't run again.
+pub fn measure(
+    day: u32,
+    puzzle: &dyn Puzzle,
+    input: &str,
+    settings: &RunSettings,
+) -> Result<DayReport, AocError> {
+    let puzzle: Arc<dyn Puzzle> = Arc::from(puzzle.with_parameters(&settings.parameters)?);
+    let mut parse = Timing::default();
+    let mut part_reports: Vec<PartReport> = settings
+        .parts
+        .iter()
+        .map(|part| PartReport {
+            part: *part,
+            answer: Ok(Answer::Number(0)),
+            timing: Timing::default(),
+        })
+        .collect();
+
+    for _ in 0..settings.repetitions.max(1) {
+        let start = Instant::now();
+        let parsed: Arc<dyn Any + Send + Sync> = Arc::from(puzzle.parse_input(input)?);
+        parse.add(start.elapsed());
+
+        for report in part_reports.iter_mut().filter(|r| r.answer.is_ok()) {
+            let (answer, duration) = solve_with_watchdog(
+                puzzle.clone(),
+                parsed.clone(),
+                report.part,
+                settings.timeout,
+            );
+            report.answer = answer;
+            report.timing.add(duration);
+        }
+    }
+
+    Ok(DayReport {
+        day,
+        parse,
+        parts: part_reports,
+    })
+}
+
+/// A table with the median timings of every given day
+pub fn summary_table(reports: &[DayReport]) -> String {
+    let column = |timing: Option<Duration>| match timing {
+        Some(duration) => format!("{:>8}", format_duration(duration)),
+        None => format!("{:>8}", "-"),
+    };
+
+    let mut table = format!(
+        "Day | {:>8} | {:>8} | {:>8} | {:>8}\n",
+        "Parse", "Part 1", "Part 2", "Total"
+    );
+    table.push_str("----+----------+----------+----------+---------\n");
+    for report in reports {
+        table.push_str(&format!(
+            "{:>3} | {} | {} | {} | {}\n",
+            report.day,
+            column(Some(report.parse.median())),
+            column(report.part(Part::One).map(|r| r.timing.median())),
+            column(report.part(Part::Two).map(|r| r.timing.median())),
+            column(Some(report.total())),
+        ));
+    }
+    table
+}
+
+/// The location of the puzzle input for the given day
+pub fn input_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("input_data/day{}/input.txt", day))
+}
+
+/// Run a day on its puzzle input. The parameters of the input, if it has any,
+/// are applied first and then the ones from the settings.
+pub fn measure_day(
+    registry: &Registry,
+    day: u32,
+    settings: &RunSettings,
+) -> Result<DayReport, Box<dyn Error>> {
+    let puzzle = registry
+        .get(day)
+        .ok_or(AocError::new(AocErrorType::UnknownDay(day)))?;
+    let path = input_path(day);
+    let input =
+        read_to_string(&path).map_err(|_| AocError::new(AocErrorType::InputDataNotFound))?;
+    let mut parameters = load_parameters(&params_path(&path))?;
+    parameters.extend(settings.parameters.clone());
+    let settings = RunSettings {
+        parameters,
+        ..settings.clone()
+    };
+
+    Ok(measure(day, puzzle, &input, &settings)?)
+}
+
+/// Run the requested parts of a day on its puzzle input and print the answers.
+pub fn run_day(registry: &Registry, day: u32, parts: &[Part]) -> Result<(), Box<dyn Error>> {
+    let settings = RunSettings {
+        parts: parts.to_vec(),
+        ..RunSettings::default()
+    };
+    measure_day(registry, day, &settings)?.print(&SubmissionLog::load(day)?);
+    Ok(())
+}
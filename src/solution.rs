@@ -0,0 +1,188 @@
+// Common interface for all the days and the registry that collects them, so
+// that every day can be run from one single executable.
+
+use crate::params::{unknown_parameter, Parameters};
+use crate::{AocError, AocErrorType};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer for one part of a puzzle. Most puzzles want a number, some
+/// (like day17) want a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(AocError::new(AocErrorType::InvalidArgument(s.to_string()))),
+        }
+    }
+}
+
+/// The solution of a single day.
+///
+/// The input is parsed only once with `parse` and the result is then handed
+/// to both parts. Malformed input should be reported as an error rather than
+/// with a panic, see `parse::ParseError`.
+///
+/// Solutions are cloned to try them with different parameters, so they should
+/// be cheap to clone. The parts run on their own thread, which is why the
+/// solution and its input have to be `Send` and `Sync`.
+pub trait Solution: Clone + Send + Sync + 'static {
+    type Input: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+    fn part1(&self, input: &Self::Input) -> Answer;
+    fn part2(&self, input: &Self::Input) -> Answer;
+
+    /// The parameters of the puzzle, like the size of a grid, with their
+    /// current values. They start out with the values for the real input.
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        vec![]
+    }
+
+    /// Change one of the parameters listed by `parameters`
+    fn set_parameter(&mut self, name: &str, _value: i64) -> Result<(), AocError> {
+        Err(unknown_parameter(name))
+    }
+}
+
+/// Object safe variant of `Solution` that hides the type of the parsed input,
+/// so that days with different input types can be kept in one `Registry`.
+///
+/// This is implemented for every `Solution`, so there should be no need to
+/// implement it by hand.
+pub trait Puzzle: Send + Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, AocError>;
+
+    /// Solve a part using an input returned by `parse_input` of the same
+    /// puzzle. Panics when given the input of another puzzle.
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer;
+
+    fn parameters(&self) -> Vec<(&'static str, i64)>;
+
+    /// A copy of the puzzle with the given parameters changed
+    fn with_parameters(&self, parameters: &Parameters) -> Result<Box<dyn Puzzle>, AocError>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, AocError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different puzzle");
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, i64)> {
+        Solution::parameters(self)
+    }
+
+    fn with_parameters(&self, parameters: &Parameters) -> Result<Box<dyn Puzzle>, AocError> {
+        let mut puzzle = self.clone();
+        for (name, value) in parameters {
+            puzzle.set_parameter(name, *value)?;
+        }
+        Ok(Box::new(puzzle))
+    }
+}
+
+/// Collection of all the days, indexed by their day number
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u32, Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn add<S: Solution>(&mut self, day: u32, solution: S) {
+        self.days.insert(day, Box::new(solution));
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn Puzzle> {
+        self.days.get(&day).map(|puzzle| puzzle.as_ref())
+    }
+
+    /// Returns an iterator over all registered days in ascending order
+    pub fn days(&self) -> impl Iterator<Item = (u32, &dyn Puzzle)> + '_ {
+        self.days
+            .iter()
+            .map(|(day, puzzle)| (*day, puzzle.as_ref()))
+    }
+}
@@ -0,0 +1,357 @@
+// Points and grids in three dimensions, for the puzzles with cubes and voxels.
+// A `Field3` covers a box of locations that doesn't have to start at the
+// origin, so the points of an input can be put into it as they are.
+
+use crate::parse::ParseError;
+use crate::TableReader;
+use std::collections::HashSet;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A cube of 3x3x3 with a hollow center, which can't be reached from the
+    // outside
+    const DROPLET: &str = "1,1,1\n2,1,1\n3,1,1\n1,2,1\n2,2,1\n3,2,1\n1,3,1\n2,3,1\n3,3,1\n\
+                           1,1,2\n2,1,2\n3,1,2\n1,2,2\n3,2,2\n1,3,2\n2,3,2\n3,3,2\n\
+                           1,1,3\n2,1,3\n3,1,3\n1,2,3\n2,2,3\n3,2,3\n1,3,3\n2,3,3\n3,3,3";
+
+    #[test]
+    fn test_parse_points() {
+        let points = parse_points("2,2,2\n-1,2,5\n").unwrap();
+        assert_eq!(points[1], Location3 { x: -1, y: 2, z: 5 });
+
+        let error = parse_points("2,2,2\n1,2\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!("3,2,1".parse::<Location3>().unwrap().z, 1);
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        let origin = Location3 { x: 0, y: 0, z: 0 };
+
+        assert_eq!(origin.neighbors(Neighborhood3::VonNeumann).count(), 6);
+        assert_eq!(origin.neighbors(Neighborhood3::Moore).count(), 26);
+        assert!(origin
+            .neighbors(Neighborhood3::VonNeumann)
+            .all(|neighbor| (neighbor - origin).manhattan() == 1));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let points = parse_points(DROPLET).unwrap();
+        let bounds = Bounds3::of(&points).unwrap();
+
+        assert_eq!(bounds.min, Location3 { x: 1, y: 1, z: 1 });
+        assert_eq!(bounds.size(), (3, 3, 3));
+        assert_eq!(bounds.expanded(1).locations().count(), 125);
+        assert!(!bounds.contains(&Location3 { x: 0, y: 1, z: 1 }));
+        assert_eq!(Bounds3::of(&[]), None);
+    }
+
+    #[test]
+    fn test_flood_fill_from_outside() {
+        let points = parse_points(DROPLET).unwrap();
+        let bounds = Bounds3::of(&points).unwrap().expanded(1);
+        let mut space = Field3::filled(bounds, false);
+        for point in &points {
+            space[*point] = true;
+        }
+
+        let outside = space.flood_fill(bounds.min, Neighborhood3::VonNeumann, |&lava| !lava);
+        assert_eq!(outside.len(), 125 - 26 - 1);
+        assert!(!outside.contains(&Location3 { x: 2, y: 2, z: 2 }));
+
+        let surfaces = points
+            .iter()
+            .flat_map(|point| point.neighbors(Neighborhood3::VonNeumann))
+            .filter(|neighbor| outside.contains(neighbor))
+            .count();
+        assert_eq!(surfaces, 54);
+    }
+}
+
+/// A point in space
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub struct Location3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Location3 {
+    /// The sum of the distances along the three axes
+    pub fn manhattan(&self) -> i32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// The surrounding locations of the neighborhood
+    pub fn neighbors(&self, neighborhood: Neighborhood3) -> impl Iterator<Item = Location3> {
+        let location = *self;
+        let offsets =
+            (-1..=1).flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| (x, y, z))));
+        offsets
+            .filter(move |&(x, y, z)| {
+                let steps = x * x + y * y + z * z;
+                match neighborhood {
+                    Neighborhood3::VonNeumann => steps == 1,
+                    Neighborhood3::Moore => steps > 0,
+                }
+            })
+            .map(move |(x, y, z)| location + Location3 { x, y, z })
+    }
+}
+
+impl Add for Location3 {
+    type Output = Location3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Location3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Location3 {
+    type Output = Location3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Location3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl std::str::FromStr for Location3 {
+    type Err = ParseError;
+
+    /// A point written as `x,y,z`
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (point,) = TableReader::new().delimiter(',').row(line)?;
+        Ok(point)
+    }
+}
+
+/// Every line of the input as a point written as `x,y,z`
+pub fn parse_points(input: &str) -> Result<Vec<Location3>, ParseError> {
+    let points = TableReader::new().delimiter(',').tuples(input)?;
+    Ok(points.into_iter().map(|(point,)| point).collect())
+}
+
+/// Which of the surrounding locations in space count as neighbors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood3 {
+    /// The six locations sharing a face
+    VonNeumann,
+    /// All 26 surrounding locations, including those sharing only an edge or
+    /// a corner
+    Moore,
+}
+
+/// A box of locations, from `min` to `max` including both
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds3 {
+    pub min: Location3,
+    pub max: Location3,
+}
+
+impl Bounds3 {
+    /// The smallest box containing all the points, `None` without points
+    pub fn of(points: &[Location3]) -> Option<Bounds3> {
+        let first = *points.first()?;
+        Some(points.iter().fold(
+            Bounds3 {
+                min: first,
+                max: first,
+            },
+            |bounds, point| Bounds3 {
+                min: Location3 {
+                    x: bounds.min.x.min(point.x),
+                    y: bounds.min.y.min(point.y),
+                    z: bounds.min.z.min(point.z),
+                },
+                max: Location3 {
+                    x: bounds.max.x.max(point.x),
+                    y: bounds.max.y.max(point.y),
+                    z: bounds.max.z.max(point.z),
+                },
+            },
+        ))
+    }
+
+    /// The box grown by `margin` on every side, to have room around the
+    /// points for a flood fill
+    pub fn expanded(&self, margin: i32) -> Bounds3 {
+        let margin = Location3 {
+            x: margin,
+            y: margin,
+            z: margin,
+        };
+        Bounds3 {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// The number of locations along x, y and z
+    pub fn size(&self) -> (usize, usize, usize) {
+        let size = self.max - self.min;
+        (
+            (size.x + 1) as usize,
+            (size.y + 1) as usize,
+            (size.z + 1) as usize,
+        )
+    }
+
+    pub fn contains(&self, location: &Location3) -> bool {
+        (self.min.x..=self.max.x).contains(&location.x)
+            && (self.min.y..=self.max.y).contains(&location.y)
+            && (self.min.z..=self.max.z).contains(&location.z)
+    }
+
+    /// All locations of the box, along x first, then y, then z
+    pub fn locations(&self) -> impl Iterator<Item = Location3> {
+        let Bounds3 { min, max } = *self;
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Location3 { x, y, z }))
+        })
+    }
+
+    // The position of the location in the entries of a `Field3`
+    fn index_of(&self, location: &Location3) -> Option<usize> {
+        if !self.contains(location) {
+            return None;
+        }
+        let (width, height, _) = self.size();
+        let offset = *location - self.min;
+        Some((offset.z as usize * height + offset.y as usize) * width + offset.x as usize)
+    }
+}
+
+/// A grid of entries for every location of a box in space
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field3<T> {
+    cells: Vec<T>,
+    bounds: Bounds3,
+}
+
+impl<T> Field3<T> {
+    /// A field with the value `f` returns for every location of the box
+    pub fn from_fn(bounds: Bounds3, f: impl FnMut(Location3) -> T) -> Field3<T> {
+        Field3 {
+            cells: bounds.locations().map(f).collect(),
+            bounds,
+        }
+    }
+
+    /// A field with the same value everywhere in the box
+    pub fn filled(bounds: Bounds3, value: T) -> Field3<T>
+    where
+        T: Clone,
+    {
+        let (width, height, depth) = bounds.size();
+        Field3 {
+            cells: vec![value; width * height * depth],
+            bounds,
+        }
+    }
+
+    pub fn bounds(&self) -> Bounds3 {
+        self.bounds
+    }
+
+    pub fn contains(&self, location: &Location3) -> bool {
+        self.bounds.contains(location)
+    }
+
+    pub fn get(&self, location: &Location3) -> Option<&T> {
+        self.bounds
+            .index_of(location)
+            .map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, location: &Location3) -> Option<&mut T> {
+        self.bounds
+            .index_of(location)
+            .map(|index| &mut self.cells[index])
+    }
+
+    pub fn at(&self, location: &Location3) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.get(location).copied()
+    }
+
+    /// Replace the entry at the given location with the new `value`.
+    /// The location must be in the box or the code will panic.
+    pub fn put(&mut self, location: &Location3, value: T) {
+        self[*location] = value;
+    }
+
+    /// All entries together with their location, see `Bounds3::locations`
+    pub fn iter(&self) -> impl Iterator<Item = (Location3, &T)> + '_ {
+        self.bounds.locations().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Location3, &mut T)> + '_ {
+        self.bounds.locations().zip(&mut self.cells)
+    }
+
+    /// The neighbors of the neighborhood that are in the box, with their value
+    pub fn actual_neighbors<'a>(
+        &'a self,
+        location: &Location3,
+        neighborhood: Neighborhood3,
+    ) -> impl Iterator<Item = (Location3, &'a T)> + 'a {
+        location
+            .neighbors(neighborhood)
+            .filter_map(|neighbor| self.get(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// All locations that can be reached from `start` by going from neighbor
+    /// to neighbor, only entering locations whose entry `can_enter` accepts.
+    /// The start itself must be accepted too, or nothing is reached.
+    pub fn flood_fill(
+        &self,
+        start: Location3,
+        neighborhood: Neighborhood3,
+        can_enter: impl Fn(&T) -> bool,
+    ) -> HashSet<Location3> {
+        let mut reached = HashSet::new();
+        let mut front = vec![start];
+        while let Some(location) = front.pop() {
+            if !self.get(&location).is_some_and(&can_enter) || !reached.insert(location) {
+                continue;
+            }
+            front.extend(
+                location
+                    .neighbors(neighborhood)
+                    .filter(|neighbor| !reached.contains(neighbor)),
+            );
+        }
+        reached
+    }
+}
+
+impl<T> Index<Location3> for Field3<T> {
+    type Output = T;
+
+    /// The entry at the location, which must be in the box
+    fn index(&self, location: Location3) -> &T {
+        self.get(&location)
+            .unwrap_or_else(|| panic!("{:?} is not in the field", location))
+    }
+}
+
+impl<T> IndexMut<Location3> for Field3<T> {
+    fn index_mut(&mut self, location: Location3) -> &mut T {
+        self.get_mut(&location)
+            .unwrap_or_else(|| panic!("{:?} is not in the field", location))
+    }
+}
@@ -0,0 +1,327 @@
+// Grids with only a few entries spread over a large or unbounded plane, like
+// the antinodes of day8. Only the locations that have an entry are stored, in
+// a hash map, and the bounding box of those locations takes the place of the
+// size of a `Field`.
+
+use crate::{Direction, Field, Location, Neighborhood};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CharacterField;
+
+    #[test]
+    fn test_bounding_box_grows() {
+        let mut field = SparseField::new();
+        assert_eq!(field.bounds(), None);
+
+        field.put(&Location { column: 3, row: -2 }, '#');
+        field.put(&Location { column: -1, row: 4 }, '#');
+
+        assert_eq!(
+            field.bounds(),
+            Some((
+                Location {
+                    column: -1,
+                    row: -2
+                },
+                Location { column: 3, row: 4 }
+            ))
+        );
+        assert_eq!((field.width(), field.height()), (5, 7));
+        assert_eq!(field.len(), 2);
+    }
+
+    #[test]
+    fn test_render_populated_extent() {
+        let mut field = SparseField::new();
+        field.put(&Location { column: 10, row: 5 }, 'A');
+        field.put(&Location { column: 12, row: 6 }, 'B');
+
+        assert_eq!(field.render('.'), "A..\n..B\n");
+        assert_eq!(field.to_string(), "A..\n..B\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut field = SparseField::new();
+        let center = Location { column: 0, row: 0 };
+        field.put(&center, 1);
+        field.put(&Location { column: 1, row: 1 }, 2);
+
+        let neighbors: Vec<_> = field
+            .actual_neighbors(&center, Neighborhood::Moore)
+            .collect();
+        assert_eq!(
+            neighbors,
+            [(
+                Location { column: 1, row: 1 },
+                Some(Direction::DownRight),
+                2
+            )]
+        );
+        assert_eq!(
+            field
+                .all_neighbors(&center, Neighborhood::VonNeumann)
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let dense = vec!["#.", ".#"].to_field().unwrap();
+
+        let mut sparse = SparseField::from_field(&dense, |&ch| ch == '#');
+        assert_eq!(sparse.len(), 2);
+        sparse.put(&Location { column: 2, row: -1 }, '#');
+
+        let grown = sparse.to_field('.');
+        assert_eq!(grown.to_string(), "..#\n#..\n.#.\n");
+
+        let everything = SparseField::from(dense);
+        assert_eq!(everything.len(), 4);
+    }
+}
+
+/// A grid that only stores the locations that have an entry. The bounding
+/// box grows with every new location and doesn't shrink when entries are
+/// removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseField<T> {
+    entries: HashMap<Location, T>,
+    bounds: Option<(Location, Location)>,
+}
+
+impl<T> Default for SparseField<T> {
+    fn default() -> Self {
+        SparseField {
+            entries: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseField<T> {
+    pub fn new() -> SparseField<T> {
+        SparseField::default()
+    }
+
+    /// The entries of a field that `keep` accepts, at the same locations
+    pub fn from_field(field: &Field<T>, mut keep: impl FnMut(&T) -> bool) -> SparseField<T>
+    where
+        T: Clone,
+    {
+        let mut sparse = SparseField::new();
+        for (location, entry) in field.iter().filter(|(_, entry)| keep(entry)) {
+            sparse.put(&location, entry.clone());
+        }
+        sparse
+    }
+
+    /// The top left and the bottom right corner of the smallest rectangle
+    /// containing every location that was ever given an entry
+    pub fn bounds(&self) -> Option<(Location, Location)> {
+        self.bounds
+    }
+
+    /// The number of columns of the bounding box
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.column - min.column + 1) as usize)
+    }
+
+    /// The number of rows of the bounding box
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.row - min.row + 1) as usize)
+    }
+
+    /// The number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether the location has an entry
+    pub fn contains(&self, location: &Location) -> bool {
+        self.entries.contains_key(location)
+    }
+
+    pub fn get(&self, location: &Location) -> Option<&T> {
+        self.entries.get(location)
+    }
+
+    pub fn get_mut(&mut self, location: &Location) -> Option<&mut T> {
+        self.entries.get_mut(location)
+    }
+
+    pub fn at(&self, location: &Location) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.get(location).copied()
+    }
+
+    /// Set the entry at the given location, which can be anywhere. Returns
+    /// the entry that was there before.
+    pub fn put(&mut self, location: &Location, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (*location, *location),
+            Some((min, max)) => (
+                Location {
+                    column: min.column.min(location.column),
+                    row: min.row.min(location.row),
+                },
+                Location {
+                    column: max.column.max(location.column),
+                    row: max.row.max(location.row),
+                },
+            ),
+        });
+        self.entries.insert(*location, value)
+    }
+
+    pub fn remove(&mut self, location: &Location) -> Option<T> {
+        self.entries.remove(location)
+    }
+
+    /// The locations that have an entry, in no particular order
+    pub fn locations(&self) -> impl Iterator<Item = Location> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// All entries together with their location, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> + '_ {
+        self.entries
+            .iter()
+            .map(|(location, entry)| (*location, entry))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Location, &mut T)> + '_ {
+        self.entries
+            .iter_mut()
+            .map(|(location, entry)| (*location, entry))
+    }
+
+    pub fn each_location(&self) -> impl Iterator<Item = (Location, T)> + '_
+    where
+        T: Copy,
+    {
+        self.iter().map(|(location, entry)| (location, *entry))
+    }
+
+    /// The neighbors of the neighborhood that have an entry, see
+    /// `Field::actual_neighbors`
+    pub fn actual_neighbors<'a>(
+        &'a self,
+        location: &Location,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Location, Option<Direction>, T)> + 'a
+    where
+        T: Copy,
+    {
+        self.all_neighbors(location, neighborhood).filter_map(
+            |(neighbor_location, direction, value)| {
+                value.map(|val| (neighbor_location, direction, val))
+            },
+        )
+    }
+
+    pub fn all_neighbors<'a>(
+        &'a self,
+        location: &Location,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Location, Option<Direction>, Option<T>)> + 'a
+    where
+        T: Copy,
+    {
+        location
+            .neighbors(neighborhood)
+            .map(|(neighbor_location, direction)| {
+                (neighbor_location, direction, self.at(&neighbor_location))
+            })
+    }
+
+    /// The bounding box as a dense field, with `fill` where there is no
+    /// entry. The top left corner of the bounding box becomes column 0 of
+    /// row 0.
+    pub fn to_field(&self, fill: T) -> Field<T>
+    where
+        T: Clone,
+    {
+        let origin = self
+            .bounds
+            .map_or(Location { column: 0, row: 0 }, |(min, _)| min);
+        Field::from_fn(self.width(), self.height(), |location| {
+            let location = Location {
+                column: location.column + origin.column,
+                row: location.row + origin.row,
+            };
+            self.get(&location).unwrap_or(&fill).clone()
+        })
+    }
+
+    /// The rows of the bounding box, with the `empty` character where there
+    /// is no entry
+    pub fn render(&self, empty: char) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut output = String::new();
+        if let Some((min, max)) = self.bounds {
+            for row in min.row..=max.row {
+                for column in min.column..=max.column {
+                    match self.get(&Location { column, row }) {
+                        Some(entry) => output.push_str(&entry.to_string()),
+                        None => output.push(empty),
+                    }
+                }
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+impl<T> From<Field<T>> for SparseField<T> {
+    /// Every entry of the field, at the same location
+    fn from(field: Field<T>) -> Self {
+        let locations: Vec<_> = field.locations().collect();
+        let mut sparse = SparseField::new();
+        for (location, entry) in locations.into_iter().zip(field.cells) {
+            sparse.put(&location, entry);
+        }
+        sparse
+    }
+}
+
+/// The populated extent, with `.` where there is no entry
+impl<T: fmt::Display> fmt::Display for SparseField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render('.'))
+    }
+}
+
+impl<T> Index<Location> for SparseField<T> {
+    type Output = T;
+
+    /// The entry at the location, which must have one
+    fn index(&self, location: Location) -> &T {
+        self.get(&location)
+            .unwrap_or_else(|| panic!("{:?} has no entry", location))
+    }
+}
+
+impl<T> IndexMut<Location> for SparseField<T> {
+    fn index_mut(&mut self, location: Location) -> &mut T {
+        self.get_mut(&location)
+            .unwrap_or_else(|| panic!("{:?} has no entry", location))
+    }
+}
@@ -0,0 +1,290 @@
+// The answers we submitted to the website and what it said about them, kept
+// next to the puzzle input in `input_data/dayN/submissions.toml`:
+//
+//     [[submission]]
+//     answer = 1234
+//     outcome = "too high"
+//     part = 1
+
+use crate::answers::{answer_from_toml, answer_to_toml};
+use crate::client::Outcome;
+use crate::{Answer, AocError, AocErrorType, Part};
+use std::fmt;
+use std::fs;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use toml::{Table, Value};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_round_trip() {
+        let mut log = SubmissionLog::default();
+        log.add(Submission {
+            part: Part::One,
+            answer: Answer::Number(1234),
+            outcome: Outcome::TooHigh,
+        });
+        log.add(Submission {
+            part: Part::Two,
+            answer: Answer::Text("6,1".into()),
+            outcome: Outcome::Correct,
+        });
+
+        let source = log.to_toml();
+        assert_eq!(SubmissionLog::parse(&source).unwrap(), log);
+    }
+
+    #[test]
+    fn test_only_verdicts_are_logged() {
+        let mut log = SubmissionLog::default();
+        log.add(Submission {
+            part: Part::One,
+            answer: Answer::Number(1234),
+            outcome: Outcome::Wait(30),
+        });
+
+        assert!(log.submissions().is_empty());
+    }
+
+    fn log_with(submissions: &[(i64, Outcome)]) -> SubmissionLog {
+        let mut log = SubmissionLog::default();
+        for (answer, outcome) in submissions {
+            log.add(Submission {
+                part: Part::One,
+                answer: Answer::Number(*answer),
+                outcome: outcome.clone(),
+            });
+        }
+        log
+    }
+
+    #[test]
+    fn test_check_against_bounds() {
+        let log = log_with(&[
+            (500, Outcome::TooHigh),
+            (300, Outcome::TooHigh),
+            (100, Outcome::TooLow),
+        ]);
+
+        assert_eq!(log.check(Part::One, &Answer::Number(200)), None);
+        assert_eq!(
+            log.check(Part::One, &Answer::Number(400)),
+            Some(Warning::TooHigh { bound: 300 })
+        );
+        assert_eq!(
+            log.check(Part::One, &Answer::Number(100)),
+            Some(Warning::AlreadyRejected)
+        );
+        assert_eq!(
+            log.check(Part::One, &Answer::Number(42)),
+            Some(Warning::TooLow { bound: 100 })
+        );
+        // The other part has its own history
+        assert_eq!(log.check(Part::Two, &Answer::Number(400)), None);
+    }
+
+    #[test]
+    fn test_check_rejected_text() {
+        let mut log = SubmissionLog::default();
+        log.add(Submission {
+            part: Part::Two,
+            answer: Answer::Text("6,1".into()),
+            outcome: Outcome::Wrong,
+        });
+
+        assert_eq!(
+            log.check(Part::Two, &Answer::Text("6,1".into())),
+            Some(Warning::AlreadyRejected)
+        );
+        assert_eq!(log.check(Part::Two, &Answer::Text("1,6".into())), None);
+    }
+
+    #[test]
+    fn test_invalid_log() {
+        assert!(SubmissionLog::parse(
+            "[[submission]]\npart = 3\nanswer = 1\noutcome = \"correct\""
+        )
+        .is_err());
+        assert!(
+            SubmissionLog::parse("[[submission]]\npart = 1\nanswer = 1\noutcome = \"meh\"")
+                .is_err()
+        );
+    }
+}
+
+/// The location of the submission log for the given day
+pub fn submissions_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("input_data/day{}/submissions.toml", day))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn parse(source: &str) -> Result<SubmissionLog, AocError> {
+        let invalid = |reason: String| AocError::new(AocErrorType::InvalidSubmissionLog(reason));
+
+        let mut table: Table = source
+            .parse()
+            .map_err(|e: toml::de::Error| invalid(e.to_string()))?;
+        let entries = match table.remove("submission") {
+            Some(Value::Array(entries)) => entries,
+            Some(_) => return Err(invalid("'submission' must be an array".to_string())),
+            None => vec![],
+        };
+
+        let mut log = SubmissionLog::default();
+        for entry in entries {
+            let mut entry = match entry {
+                Value::Table(entry) => entry,
+                _ => return Err(invalid("a submission must be a table".to_string())),
+            };
+            let part = match entry.remove("part").and_then(|v| v.as_integer()) {
+                Some(1) => Part::One,
+                Some(2) => Part::Two,
+                _ => return Err(invalid("'part' must be 1 or 2".to_string())),
+            };
+            let answer = entry
+                .remove("answer")
+                .and_then(answer_from_toml)
+                .ok_or(invalid("'answer' must be a number or a string".to_string()))?;
+            let outcome = match entry.remove("outcome").as_ref().and_then(|v| v.as_str()) {
+                Some("correct") => Outcome::Correct,
+                Some("too high") => Outcome::TooHigh,
+                Some("too low") => Outcome::TooLow,
+                Some("wrong") => Outcome::Wrong,
+                _ => return Err(invalid("unknown 'outcome'".to_string())),
+            };
+            log.add(Submission {
+                part,
+                answer,
+                outcome,
+            });
+        }
+        Ok(log)
+    }
+
+    /// Load the submissions of the given day, no file means no submissions
+    pub fn load(day: u32) -> Result<SubmissionLog, AocError> {
+        match read_to_string(submissions_path(day)) {
+            Ok(source) => SubmissionLog::parse(&source),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(error) => Err(AocError::new(AocErrorType::InvalidSubmissionLog(
+                error.to_string(),
+            ))),
+        }
+    }
+
+    pub fn save(&self, day: u32) -> Result<(), AocError> {
+        fs::write(submissions_path(day), self.to_toml())
+            .map_err(|e| AocError::new(AocErrorType::WriteFailed(e.to_string())))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let entries: Vec<Value> = self
+            .submissions
+            .iter()
+            .map(|submission| {
+                let mut entry = Table::new();
+                entry.insert(
+                    "part".to_string(),
+                    Value::Integer(match submission.part {
+                        Part::One => 1,
+                        Part::Two => 2,
+                    }),
+                );
+                entry.insert("answer".to_string(), answer_to_toml(&submission.answer));
+                entry.insert(
+                    "outcome".to_string(),
+                    Value::String(submission.outcome.to_string()),
+                );
+                Value::Table(entry)
+            })
+            .collect();
+
+        let mut table = Table::new();
+        table.insert("submission".to_string(), Value::Array(entries));
+        table.to_string()
+    }
+
+    /// Record a submission. Only answers the website actually judged are
+    /// kept, a request to wait says nothing about the answer.
+    pub fn add(&mut self, submission: Submission) {
+        if submission.outcome.is_verdict() {
+            self.submissions.push(submission);
+        }
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Check an answer against the earlier submissions of the same part
+    pub fn check(&self, part: Part, answer: &Answer) -> Option<Warning> {
+        let earlier = self.submissions.iter().filter(|s| s.part == part);
+        if earlier
+            .clone()
+            .any(|s| s.outcome != Outcome::Correct && s.answer == *answer)
+        {
+            return Some(Warning::AlreadyRejected);
+        }
+
+        let Answer::Number(number) = answer else {
+            return None;
+        };
+        let numbers_with = |outcome: Outcome| {
+            earlier.clone().filter_map(move |s| match s.answer {
+                Answer::Number(n) if s.outcome == outcome => Some(n),
+                _ => None,
+            })
+        };
+        if let Some(bound) = numbers_with(Outcome::TooHigh).min() {
+            if *number >= bound {
+                return Some(Warning::TooHigh { bound });
+            }
+        }
+        if let Some(bound) = numbers_with(Outcome::TooLow).max() {
+            if *number <= bound {
+                return Some(Warning::TooLow { bound });
+            }
+        }
+        None
+    }
+}
+
+/// Why an answer is most likely wrong, based on the earlier submissions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    AlreadyRejected,
+    /// `bound` was already too high, so the answer must be smaller
+    TooHigh {
+        bound: i64,
+    },
+    /// `bound` was already too low, so the answer must be bigger
+    TooLow {
+        bound: i64,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::AlreadyRejected => write!(f, "this answer was already rejected"),
+            Warning::TooHigh { bound } => write!(f, "{} was already too high", bound),
+            Warning::TooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
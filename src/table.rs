@@ -0,0 +1,244 @@
+// Reading lines of values, like the two columns of day1 or the `|` separated
+// rules of day5. Every line is a row and the values of a row are separated by
+// whitespace or by a delimiter. The values can be anything that a `{}` of a
+// line pattern can be, see `parse::FromCaptures`.
+
+use crate::parse::{parse_lines, Capture, FromCaptures, ParseError};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Location;
+
+    #[test]
+    fn test_rows() {
+        let rows: Vec<Vec<i32>> = TableReader::new().rows("7 6 4 2 1\n1 2 7\n").unwrap();
+
+        assert_eq!(rows, vec![vec![7, 6, 4, 2, 1], vec![1, 2, 7]]);
+    }
+
+    #[test]
+    fn test_tuples_with_delimiter() {
+        let rules: Vec<(i32, i32)> = TableReader::new()
+            .delimiter('|')
+            .tuples("47|53\n97|13")
+            .unwrap();
+        assert_eq!(rules, vec![(47, 53), (97, 13)]);
+
+        let error = TableReader::new()
+            .delimiter('|')
+            .tuples::<(i32, i32)>("47|53\n97|13|61")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.message, "expected 2 values, found 3");
+    }
+
+    #[test]
+    fn test_columns() {
+        let [left, right] = TableReader::new()
+            .columns::<i32, 2>("3   4\n4   3\n2   5")
+            .unwrap();
+        assert_eq!(left, vec![3, 4, 2]);
+        assert_eq!(right, vec![4, 3, 5]);
+
+        let error = TableReader::new()
+            .columns::<i32, 2>("3   4\n4")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let reader = TableReader::new().delimiter(',');
+        assert!(reader.rectangular::<i64>("1,2\n3,4").is_ok());
+
+        let error = reader.rectangular::<i64>("1,2\n3,4,5").unwrap_err();
+        assert_eq!(
+            error.message,
+            "expected 2 values like the first row, found 3"
+        );
+    }
+
+    #[test]
+    fn test_values_using_several_cells() {
+        let rows: Vec<(Location, i32)> = TableReader::new()
+            .delimiter(',')
+            .tuples("5,4,1\n4,2,7")
+            .unwrap();
+
+        assert_eq!(rows[1], (Location { column: 4, row: 2 }, 7));
+    }
+}
+
+/// Reads lines of values separated by whitespace, or by a delimiter set with
+/// `delimiter`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableReader {
+    delimiter: Option<char>,
+}
+
+impl TableReader {
+    pub fn new() -> TableReader {
+        TableReader::default()
+    }
+
+    /// Separate the values by `delimiter` instead of whitespace. Whitespace
+    /// around the values is ignored.
+    pub fn delimiter(mut self, delimiter: char) -> TableReader {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    fn cells<'a>(&self, line: &'a str) -> Vec<Capture<'a>> {
+        let texts: Vec<&str> = match self.delimiter {
+            None => line.split_whitespace().collect(),
+            Some(delimiter) => line.split(delimiter).map(str::trim).collect(),
+        };
+        texts
+            .into_iter()
+            .map(|text| Capture {
+                // The text is a part of the line, so this is its position
+                offset: text.as_ptr() as usize - line.as_ptr() as usize,
+                text,
+            })
+            .collect()
+    }
+
+    /// The values of one line as a tuple like `(i32, i32)`. The line needs
+    /// exactly the values of the tuple.
+    pub fn row<R: Row>(&self, line: &str) -> Result<R, ParseError> {
+        let cells = self.cells(line);
+        if cells.len() != R::CELLS {
+            return Err(ragged(
+                line,
+                &cells,
+                R::CELLS,
+                format!("expected {} values, found {}", R::CELLS, cells.len()),
+            ));
+        }
+        R::from_cells(line, &cells)
+    }
+
+    /// Every line as a row of any length
+    pub fn rows<T: FromCaptures>(&self, input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+        parse_lines(input, |line| {
+            let cells = self.cells(line);
+            if !cells.len().is_multiple_of(T::CAPTURES) {
+                let expected = cells.len() / T::CAPTURES * T::CAPTURES + T::CAPTURES;
+                return Err(ragged(
+                    line,
+                    &cells,
+                    expected,
+                    format!("expected {} more values", expected - cells.len()),
+                ));
+            }
+            cells
+                .chunks(T::CAPTURES)
+                .map(|chunk| T::from_captures(line, chunk))
+                .collect()
+        })
+    }
+
+    /// Every line as a row, all of the same length
+    pub fn rectangular<T: FromCaptures>(&self, input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+        let rows = self.rows::<T>(input)?;
+        if let Some(first) = rows.first() {
+            let width = first.len() * T::CAPTURES;
+            if let Some((index, line)) = input
+                .lines()
+                .enumerate()
+                .zip(&rows)
+                .find(|(_, row)| row.len() != first.len())
+                .map(|(line, _)| line)
+            {
+                let cells = self.cells(line);
+                return Err(ragged(
+                    line,
+                    &cells,
+                    width,
+                    format!(
+                        "expected {} values like the first row, found {}",
+                        width,
+                        cells.len()
+                    ),
+                )
+                .on_line(index + 1, line));
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Every line as a tuple like `(i32, i32)`
+    pub fn tuples<R: Row>(&self, input: &str) -> Result<Vec<R>, ParseError> {
+        parse_lines(input, |line| self.row(line))
+    }
+
+    /// The columns of lines with `N` values each
+    pub fn columns<T: FromCaptures, const N: usize>(
+        &self,
+        input: &str,
+    ) -> Result<[Vec<T>; N], ParseError> {
+        let rows = parse_lines(input, |line| {
+            let cells = self.cells(line);
+            if cells.len() != N * T::CAPTURES {
+                return Err(ragged(
+                    line,
+                    &cells,
+                    N * T::CAPTURES,
+                    format!("expected {} values, found {}", N * T::CAPTURES, cells.len()),
+                ));
+            }
+            cells
+                .chunks(T::CAPTURES)
+                .map(|chunk| T::from_captures(line, chunk))
+                .collect::<Result<Vec<T>, ParseError>>()
+        })?;
+
+        let mut columns: [Vec<T>; N] = std::array::from_fn(|_| vec![]);
+        for row in rows {
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        Ok(columns)
+    }
+}
+
+// The error for a row that doesn't have the `expected` number of cells. It
+// points at the first value too many or at the end of the line.
+fn ragged(line: &str, cells: &[Capture], expected: usize, message: String) -> ParseError {
+    match cells.get(expected) {
+        Some(cell) => ParseError::new(line, cell.offset, cell.text, &message),
+        None => ParseError::new(line, line.len(), "", &message),
+    }
+}
+
+/// Tuples of values that make up a row of a table
+pub trait Row: Sized {
+    /// How many values of the row the tuple takes
+    const CELLS: usize;
+
+    fn from_cells(line: &str, cells: &[Capture]) -> Result<Self, ParseError>;
+}
+
+macro_rules! tuple_rows {
+    ($(($($value:ident),+)),*) => {
+        $(
+            impl<$($value: FromCaptures),+> Row for ($($value,)+) {
+                const CELLS: usize = 0 $(+ $value::CAPTURES)+;
+
+                #[allow(unused_assignments)]
+                fn from_cells(line: &str, cells: &[Capture]) -> Result<Self, ParseError> {
+                    let mut start = 0;
+                    Ok(($({
+                        let value = $value::from_captures(line, &cells[start..start + $value::CAPTURES])?;
+                        start += $value::CAPTURES;
+                        value
+                    },)+))
+                }
+            }
+        )*
+    };
+}
+
+tuple_rows!((A), (A, B), (A, B, C), (A, B, C, D));
@@ -0,0 +1,232 @@
+// Grids of characters, like the warehouse of day15 or the maze of day16. The
+// tiles of a grid derive `Tile`, which maps every character to a tile and
+// back. Markers like the `@` of the robot or the `S` and `E` of the maze
+// aren't tiles of their own, they stand on a tile and their locations are
+// collected while reading the grid. Grids without a `Tile` type can be read
+// with `Field::parse` and a function that maps the characters.
+
+use crate::parse::ParseError;
+use crate::{Field, Location, Topology};
+use std::collections::HashMap;
+use std::ops::Deref;
+
+pub use aoc2024_derive::Tile;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Tile, Debug, Clone, Copy, PartialEq)]
+    enum Place {
+        #[tile('.', marker = 'S', marker = 'E')]
+        Walkable(i32),
+        #[tile('#')]
+        Wall,
+    }
+
+    #[derive(Tile, Debug, Clone, Copy, PartialEq)]
+    struct Plot {
+        #[tile(char)]
+        plant_type: char,
+        assigned: bool,
+    }
+
+    #[test]
+    fn test_characters_in_both_directions() {
+        assert_eq!(Place::try_from('#').unwrap(), Place::Wall);
+        assert_eq!(Place::try_from('E').unwrap(), Place::Walkable(0));
+        assert_eq!(Place::Walkable(12).to_string(), ".");
+        assert!(Place::is_marker('S') && !Place::is_marker('.'));
+
+        let error = Place::try_from('x').unwrap_err();
+        assert_eq!(error.message, "expected one of \".SE#\"");
+    }
+
+    #[test]
+    fn test_parse_tiles_with_markers() {
+        let (maze, markers) = parse_tiles::<Place>("###\n#SE\n").unwrap();
+
+        assert_eq!(maze.to_string(), "###\n#..\n");
+        assert_eq!(markers.get('S'), Some(Location { column: 1, row: 1 }));
+        assert_eq!(markers.all('E'), [Location { column: 2, row: 1 }]);
+        assert_eq!(markers.get('@'), None);
+
+        let error = parse_tiles::<Place>("###\n#.x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let mapper = |ch: char| match ch {
+            '0' => Some(Cell::Marker(0)),
+            '1'..='9' => ch.to_digit(10).map(|height| Cell::Tile(height as i32)),
+            _ => None,
+        };
+        let (map, markers) = Field::parse("0123\n1230\r\n\n  \n", mapper).unwrap();
+
+        assert_eq!(map.at(&Location { column: 2, row: 1 }), Some(3));
+        assert_eq!(markers[&'0'].len(), 2);
+        assert_eq!(markers.all('0')[1], Location { column: 3, row: 1 });
+
+        let error = Field::parse("0123\n12301", mapper).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.message,
+            "expected 4 tiles like the first row, found 5"
+        );
+
+        let error = Field::parse("0123\n12\n", mapper).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Field::parse("0123\n12a3\n", mapper).unwrap_err();
+        assert_eq!(error.text, "a");
+    }
+
+    #[test]
+    fn test_any_character() {
+        let (garden, _) = parse_tiles::<Plot>("AB\nBB").unwrap();
+
+        assert_eq!(
+            garden.at(&Location { column: 1, row: 0 }),
+            Some(Plot {
+                plant_type: 'B',
+                assigned: false
+            })
+        );
+        assert_eq!(garden.to_string(), "AB\nBB\n");
+    }
+}
+
+/// A tile of a grid that is written as a single character
+pub trait Tile: Sized {
+    /// The characters of all tiles and markers, empty when any character is a
+    /// tile
+    const CHARACTERS: &'static str;
+
+    /// The tile for a character, markers give the tile they stand on
+    fn from_char(ch: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+
+    /// Whether the character marks a location, like the `@` of the robot
+    fn is_marker(_ch: char) -> bool {
+        false
+    }
+}
+
+/// The message for a character that isn't a tile of `T`
+pub fn unknown_tile<T: Tile>() -> String {
+    format!("expected one of \"{}\"", T::CHARACTERS)
+}
+
+/// The locations of the markers found in a grid, by their character
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Markers(HashMap<char, Vec<Location>>);
+
+impl Deref for Markers {
+    type Target = HashMap<char, Vec<Location>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Markers {
+    /// Where the marker is, for a marker that appears once like a start
+    pub fn get(&self, marker: char) -> Option<Location> {
+        self.all(marker).first().copied()
+    }
+
+    /// All locations of the marker, from top left to bottom right
+    pub fn all(&self, marker: char) -> &[Location] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// What a character of a grid stands for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cell<T> {
+    Tile(T),
+    /// A marker, like the `^` of the guard, standing on the tile
+    Marker(T),
+}
+
+/// Read a grid of tiles, every line of the input is a row. See `Field::parse`.
+pub fn parse_tiles<T: Tile>(input: &str) -> Result<(Field<T>, Markers), ParseError> {
+    parse_grid(
+        input,
+        |ch| {
+            T::from_char(ch).map(|tile| match T::is_marker(ch) {
+                true => Cell::Marker(tile),
+                false => Cell::Tile(tile),
+            })
+        },
+        &unknown_tile::<T>(),
+    )
+}
+
+// The rows of the grid and the locations of its markers. `unknown` is the
+// message for a character the mapper doesn't know.
+pub(crate) fn parse_grid<T>(
+    input: &str,
+    mapper: impl Fn(char) -> Option<Cell<T>>,
+    unknown: &str,
+) -> Result<(Field<T>, Markers), ParseError> {
+    let mut lines: Vec<&str> = input
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let mut markers = Markers::default();
+    let mut cells = vec![];
+    let mut width = None;
+    for (row, line) in lines.into_iter().enumerate() {
+        let error = |offset: usize, text: &str, message: &str| {
+            ParseError::new(line, offset, text, message).on_line(row + 1, line)
+        };
+
+        let mut columns = 0;
+        for (column, (offset, ch)) in line.char_indices().enumerate() {
+            let location = Location {
+                column: column as i32,
+                row: row as i32,
+            };
+            match mapper(ch) {
+                Some(Cell::Tile(tile)) => cells.push(tile),
+                Some(Cell::Marker(tile)) => {
+                    markers.0.entry(ch).or_default().push(location);
+                    cells.push(tile);
+                }
+                None => return Err(error(offset, &ch.to_string(), unknown)),
+            }
+            columns += 1;
+        }
+
+        match width {
+            None => width = Some(columns),
+            Some(width) if columns != width => {
+                let message = format!(
+                    "expected {} tiles like the first row, found {}",
+                    width, columns
+                );
+                // Point at the first tile too many or at the end of the row
+                let offset = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(i, _)| i);
+                return Err(error(offset, &line[offset..], &message));
+            }
+            Some(_) => {}
+        }
+    }
+
+    let field = Field {
+        cells,
+        width: width.unwrap_or(0),
+        topology: Topology::Bounded,
+    };
+    Ok((field, markers))
+}
@@ -6,64 +6,117 @@ use std::io::Write;
 use std::path::Path;
 use toml::Table;
 
-const RUST_TEMPLATE: &str = r##"use std::error::Error;
-use std::fs::read_to_string;
+const RUST_TEMPLATE: &str = r##"//! Day {day_number}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+use crate::{Answer, AocError, Registry, Solution};
 
-    const SIMPLE_INPUT: &str = r#"
-    "#;
-
-    #[test]
-    fn test_simple_input_part1() {
-        assert_eq!(challenge1(SIMPLE_INPUT), 0);
-    }
-
-    #[test]
-    fn test_simple_input_part2() {
-        assert_eq!(challenge2(SIMPLE_INPUT), 0);
-    }
+pub fn parse_input(challenge_input: &str) -> String {
+    challenge_input.to_string()
 }
 
-fn challenge1(_challenge_input: &str) -> i64 {
+pub fn challenge1(_input: &str) -> i64 {
     42
 }
 
-fn challenge2(_challenge_input: &str) -> i64 {
+pub fn challenge2(_input: &str) -> i64 {
     42
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let test_input = read_to_string("input_data/{day_name}/input.txt")?;
+#[derive(Clone)]
+pub struct {struct_name};
 
-    let result1 = challenge1(&test_input);
-    let result2 = challenge2(&test_input);
+impl Solution for {struct_name} {
+    type Input = String;
 
-    println!("Answer part 1: {}", result1);
-    println!("Answer part 2: {}", result2);
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_input(input))
+    }
 
-    Ok(())
+    fn part1(&self, input: &Self::Input) -> Answer {
+        challenge1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Answer {
+        challenge2(input).into()
+    }
 }
+
+pub fn register(registry: &mut Registry) {
+    registry.add({day_number}, {struct_name});
+}
+"##;
+
+const EXAMPLES_TEMPLATE: &str = r##"# Answers given in the puzzle description for example1.txt
+[example1]
+# part1 = 0
+# part2 = 0
 "##;
 
+const BIN_TEMPLATE: &str = r##"use aoc2024::{registry, run_day, Part};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    run_day(&registry(), {day_number}, &Part::BOTH)
+}
+"##;
+
+// Add the new day to the `days!` list in lib.rs, so that it gets registered
+fn add_to_days_list(lib_source: &str, day_name: &str) -> Result<String, AocError> {
+    let list_start =
+        lib_source
+            .find("days! {")
+            .ok_or(AocError::new(AocErrorType::InvalidArgument(
+                "src/lib.rs".to_string(),
+            )))?;
+    let list_end = list_start
+        + lib_source[list_start..].find("\n}").ok_or(AocError::new(
+            AocErrorType::InvalidArgument("src/lib.rs".to_string()),
+        ))?;
+
+    Ok(format!(
+        "{}\n    {},{}",
+        &lib_source[..list_end],
+        day_name,
+        &lib_source[list_end..]
+    ))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let day_name = get_arg1().ok_or(AocError::new(AocErrorType::MissingArgument))?;
+    let day_number = day_name
+        .trim_start_matches("day")
+        .parse::<u32>()
+        .map_err(|_| AocError::new(AocErrorType::InvalidArgument(day_name.clone())))?;
+    let struct_name = format!("Day{}", day_number);
 
     let rust_file_name = day_name.to_string() + ".rs";
 
     let new_data_dir = format!("input_data/{}", &day_name);
     fs::create_dir_all(&new_data_dir)?;
+    fs::write(Path::new(&new_data_dir).join("example1.txt"), "")?;
+    fs::write(
+        Path::new(&new_data_dir).join("examples.toml"),
+        EXAMPLES_TEMPLATE,
+    )?;
     let new_src_dir = format!("src/{}", &day_name);
     fs::create_dir_all(&new_src_dir)?;
+    fs::write(
+        Path::new(&new_src_dir).join("mod.rs"),
+        RUST_TEMPLATE
+            .replace("{struct_name}", &struct_name)
+            .replace("{day_number}", &day_number.to_string()),
+    )
+    .expect("Couldn't write to output file");
     fs::write(
         Path::new(&new_src_dir).join(&rust_file_name),
-        RUST_TEMPLATE.replace("{day_name}", &day_name),
+        BIN_TEMPLATE.replace("{day_number}", &day_number.to_string()),
     )
     .expect("Couldn't write to output file");
 
-    let toml_data = read_to_string("cargo.toml")?;
+    let lib_source = read_to_string("src/lib.rs")?;
+    fs::write("src/lib.rs", add_to_days_list(&lib_source, &day_name)?)?;
+
+    let toml_data = read_to_string("Cargo.toml")?;
     let mut main_table = toml_data.parse::<Table>()?;
     let mut new_entry = Table::new();
     new_entry.insert("name".to_string(), day_name.clone().into());
@@ -0,0 +1,122 @@
+use aoc2024::client::{fetch_input, AocClient, Outcome, USER_AGENT};
+use aoc2024::config::Config;
+use aoc2024::{Answer, Part};
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+// A stand-in for the website that answers every request with the given status
+// and body, and hands the received requests back to the test
+fn serve(status: &'static str, body: &'static str) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some(length) = line.strip_prefix("Content-Length: ") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            if sender.send(request).is_err() {
+                break;
+            }
+        }
+    });
+
+    (base_url, receiver)
+}
+
+fn client_for(base_url: &str) -> AocClient {
+    AocClient::new(&Config {
+        session: Some("s3cr3t".to_string()),
+        base_url: base_url.to_string(),
+    })
+    .unwrap()
+}
+
+fn cache_path(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("aoc2024-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory.join("input.txt")
+}
+
+#[test]
+fn test_fetch_downloads_once() {
+    let (base_url, requests) = serve("200 OK", "3   4\n4   3\n");
+    let client = client_for(&base_url);
+    let cache = cache_path("fetch");
+
+    assert!(fetch_input(&client, 1, &cache).unwrap());
+    assert_eq!(fs::read_to_string(&cache).unwrap(), "3   4\n4   3\n");
+
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+    assert!(request.contains("Cookie: session=s3cr3t"));
+    assert!(request.contains(&format!("User-Agent: {}", USER_AGENT)));
+
+    // The second time the input comes from the cache
+    assert!(!fetch_input(&client, 1, &cache).unwrap());
+    assert!(requests.try_recv().is_err());
+
+    fs::remove_dir_all(cache.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_failed_download_is_not_cached() {
+    let (base_url, _requests) = serve("400 Bad Request", "Please log in.");
+    let client = client_for(&base_url);
+    let cache = cache_path("failed");
+
+    assert!(fetch_input(&client, 2, &cache).is_err());
+    assert!(!cache.exists());
+}
+
+#[test]
+fn test_submit_answer() {
+    let (base_url, requests) = serve(
+        "200 OK",
+        "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+    );
+    let client = client_for(&base_url);
+
+    let outcome = client
+        .submit_answer(7, Part::Two, &Answer::Number(11387))
+        .unwrap();
+
+    assert_eq!(outcome, Outcome::TooLow);
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("POST /2024/day/7/answer HTTP/1.1"));
+    assert!(request.contains("Cookie: session=s3cr3t"));
+    assert!(request.ends_with("level=2&answer=11387"));
+}
+
+#[test]
+fn test_client_needs_a_session() {
+    assert!(AocClient::new(&Config::default()).is_err());
+}
@@ -0,0 +1,24 @@
+use aoc2024::answers::Verdict;
+use aoc2024::examples::{check_example, load_examples};
+use aoc2024::registry;
+
+// Runs every example found in input_data/dayN/examples.toml
+#[test]
+fn test_all_examples() {
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for (day, _) in registry().days() {
+        for example in load_examples(day).unwrap() {
+            for verdict in check_example(day, &example).unwrap() {
+                checked += 1;
+                if verdict.verdict != Verdict::Pass {
+                    failures.push(format!("Day {} {} {}", day, example.name, verdict));
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "No examples found in input_data");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
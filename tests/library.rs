@@ -0,0 +1,276 @@
+// Make sure that the building blocks of the days can be used from outside the crate
+
+use aoc2024::day13::{solve_claw_machine, ClawMachine};
+use aoc2024::day16::walk_maze_cost;
+use aoc2024::day17::{Computer, Instruction, ADV, JNZ, OUT};
+use aoc2024::{
+    CharacterField, Direction, DirectionRelative, Field, FieldLine, Location, Neighborhood,
+    Position, Topology,
+};
+
+#[test]
+fn test_solving_a_claw_machine() {
+    let machine = ClawMachine {
+        button_a: Position { x: 94, y: 34 },
+        button_b: Position { x: 22, y: 67 },
+        prize: Position { x: 8400, y: 5400 },
+    };
+
+    assert_eq!(solve_claw_machine(&machine), Some(280));
+}
+
+#[test]
+fn test_running_a_program_on_the_computer() {
+    let mut computer = Computer {
+        register_a: 729,
+        register_b: 0,
+        register_c: 0,
+        instruction_pointer: 0,
+        program: vec![
+            Instruction {
+                opcode: ADV,
+                operand: 1,
+            },
+            Instruction {
+                opcode: OUT,
+                operand: 4,
+            },
+            Instruction {
+                opcode: JNZ,
+                operand: 0,
+            },
+        ],
+    };
+
+    assert_eq!(computer.run_to_completion(), "4,6,3,5,6,3,5,2,1,0");
+}
+
+#[test]
+fn test_walking_a_maze() {
+    let (mut maze, start, end) = aoc2024::day16::parse_input("#####\n#..E#\n#S#.#\n#####").unwrap();
+
+    assert_eq!(
+        walk_maze_cost(&mut maze, &start, &end, Direction::Right),
+        2003
+    );
+}
+
+#[test]
+fn test_field_access() {
+    let mut field = Field::from_fn(3, 2, |location| location.column + 10 * location.row);
+
+    assert_eq!((field.width(), field.height()), (3, 2));
+    assert_eq!(field[Location { column: 2, row: 1 }], 12);
+    assert!(!field.contains(&Location { column: -1, row: 1 }));
+    assert_eq!(field.get(&Location { column: 3, row: 0 }), None);
+    assert_eq!(field.at_mut(&Location { column: -1, row: 1 }), None);
+
+    for (location, value) in field.iter_mut() {
+        if location.row == 0 {
+            *value = -*value;
+        }
+    }
+    field[Location { column: 0, row: 1 }] = 7;
+
+    let doubled = field.map(|value| value * 2);
+    let pairs = field.zip(&doubled);
+    assert_eq!(pairs[Location { column: 1, row: 0 }], (-1, -2));
+    assert_eq!(pairs[Location { column: 0, row: 1 }], (7, 14));
+    assert_eq!(Field::filled(2, 2, '.').to_string(), "..\n..\n");
+}
+
+#[test]
+fn test_field_rows() {
+    let mut field = Field::new(vec![vec!['#', '.', '#'], vec!['.', '.', '#']]);
+
+    assert_eq!(field.row(1), Some(&['.', '.', '#'][..]));
+    assert_eq!(field.row(2), None);
+    field.row_mut(0).unwrap()[1] = '#';
+    assert_eq!(field.rows().filter(|row| row[1] == '#').count(), 1);
+
+    let location = Location { column: 2, row: 1 };
+    assert_eq!(field.index_of(&location), Some(5));
+    assert_eq!(field.location_of(5), location);
+    assert_eq!(field.index_of(&Location { column: 3, row: 0 }), None);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn test_field_rows_must_have_the_same_length() {
+    Field::new(vec![vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn test_character_field_to_field() {
+    let field = vec!["ab\r", "cd\r", ""].to_field().unwrap();
+    assert_eq!(field.to_string(), "ab\ncd\n");
+
+    let error = vec!["abc", "de"].to_field().unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+}
+
+fn line_text(line: FieldLine<char>) -> String {
+    line.into_iter().map(|(_, ch)| *ch).collect()
+}
+
+#[test]
+fn test_field_lines() {
+    let field = vec!["abcd", "efgh", "ijkl"].to_field().unwrap();
+
+    assert_eq!(
+        field.main_diagonals().map(line_text).collect::<Vec<_>>(),
+        ["d", "ch", "bgl", "afk", "ej", "i"]
+    );
+    assert_eq!(
+        field.anti_diagonals().map(line_text).collect::<Vec<_>>(),
+        ["a", "eb", "ifc", "jgd", "kh", "l"]
+    );
+    assert_eq!(
+        field.column_lines().map(line_text).collect::<Vec<_>>(),
+        ["aei", "bfj", "cgk", "dhl"]
+    );
+    assert_eq!(
+        field.row_lines().nth(1).unwrap()[2],
+        (Location { column: 2, row: 1 }, &'g')
+    );
+}
+
+#[test]
+fn test_field_transforms() {
+    let field = vec!["abc", "def"].to_field().unwrap();
+
+    assert_eq!(field.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(field.rotate_cw().to_string(), "da\neb\nfc\n");
+    assert_eq!(field.rotate_ccw().to_string(), "cf\nbe\nad\n");
+    assert_eq!(field.flip_horizontal().to_string(), "cba\nfed\n");
+    assert_eq!(field.flip_vertical().to_string(), "def\nabc\n");
+    assert_eq!(field.rotate_cw().rotate_ccw(), field);
+}
+
+#[test]
+fn test_neighborhoods() {
+    let field = vec!["abc", "def", "ghi"].to_field().unwrap();
+    let corner = Location { column: 0, row: 0 };
+    let center = Location { column: 1, row: 1 };
+
+    assert_eq!(
+        field.actual_neighbors(&center, Neighborhood::Moore).count(),
+        8
+    );
+    assert_eq!(
+        field.actual_neighbors(&corner, Neighborhood::Moore).count(),
+        3
+    );
+    assert_eq!(
+        field
+            .all_neighbors(&corner, Neighborhood::VonNeumann)
+            .count(),
+        4
+    );
+
+    let diagonal: Vec<_> = field
+        .actual_neighbors(&corner, Neighborhood::Diagonal)
+        .collect();
+    assert_eq!(diagonal, [(center, Some(Direction::DownRight), 'e')]);
+
+    let upwards = [(0, -1), (1, -1)];
+    let (location, direction) = center
+        .neighbors(Neighborhood::Custom(&upwards))
+        .last()
+        .unwrap();
+    assert_eq!(location, Location { column: 2, row: 0 });
+    assert_eq!(
+        direction.unwrap() + DirectionRelative::Right,
+        Direction::DownRight
+    );
+}
+
+#[test]
+fn test_neighbors_further_away() {
+    let field = vec!["abcd", "efgh", "ijkl"].to_field().unwrap();
+    let knight = [
+        (1, 2),
+        (2, 1),
+        (-1, 2),
+        (-2, 1),
+        (1, -2),
+        (2, -1),
+        (-1, -2),
+        (-2, -1),
+    ];
+
+    let moves: Vec<_> = field
+        .actual_neighbors(
+            &Location { column: 0, row: 0 },
+            Neighborhood::Custom(&knight),
+        )
+        .collect();
+    assert_eq!(
+        moves,
+        [
+            (Location { column: 1, row: 2 }, None, 'j'),
+            (Location { column: 2, row: 1 }, None, 'g')
+        ]
+    );
+
+    let ring = [(-2, 0), (2, 0), (0, -2), (0, 2)];
+    let (_, direction) = Location { column: 0, row: 0 }
+        .neighbors(Neighborhood::Custom(&ring))
+        .next()
+        .unwrap();
+    assert_eq!(direction, None);
+}
+
+#[test]
+fn test_toroidal_field() {
+    let field = vec!["abc", "def"].to_field().unwrap();
+    let corner = Location { column: 0, row: 0 };
+
+    assert_eq!(field.at(&Location { column: -1, row: 0 }), None);
+    assert_eq!(field.in_direction(&corner, Direction::Up), None);
+    assert_eq!(
+        field.wrap(&Location {
+            column: -301,
+            row: 7
+        }),
+        Location { column: 2, row: 1 }
+    );
+
+    let field = field.with_topology(Topology::Toroidal);
+    assert_eq!(field.at(&Location { column: -1, row: 0 }), Some('c'));
+    assert_eq!(field[Location { column: 4, row: -3 }], 'e');
+    assert_eq!(
+        field.in_direction(&corner, Direction::UpLeft),
+        Some(Location { column: 2, row: 1 })
+    );
+    let neighbors: Vec<_> = field
+        .actual_neighbors(&corner, Neighborhood::VonNeumann)
+        .map(|(location, _, value)| (location, value))
+        .collect();
+    assert_eq!(
+        neighbors,
+        [
+            (Location { column: 2, row: 0 }, 'c'),
+            (Location { column: 1, row: 0 }, 'b'),
+            (Location { column: 0, row: 1 }, 'd'),
+            (Location { column: 0, row: 1 }, 'd')
+        ]
+    );
+    assert!(field
+        .all_neighbors(&corner, Neighborhood::Moore)
+        .all(|(location, _, value)| field.contains(&location) && value.is_some()));
+}
+
+#[test]
+fn test_toroidal_field_lines_stop_at_the_edges() {
+    let field = vec!["abc", "def"]
+        .to_field()
+        .unwrap()
+        .with_topology(Topology::Toroidal);
+
+    let rows: Vec<String> = field.row_lines().map(line_text).collect();
+    assert_eq!(rows, ["abc", "def"]);
+    assert_eq!(field.column_lines().count(), 3);
+    assert!(field.main_diagonals().all(|line| line.len() <= 2));
+    assert_eq!(field.anti_diagonals().count(), 4);
+}